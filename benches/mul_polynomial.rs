@@ -0,0 +1,167 @@
+#![feature(test)]
+
+extern crate test;
+
+use core::ops::{Add, Mul, Sub};
+
+use polynomial_ops::*;
+use test::{black_box, Bencher};
+
+type M = ModInt<NTT_PRIME>;
+
+/// A coefficient without subtraction, so that slices of it are always multiplied by direct convolution
+#[derive(Clone, Copy, Default)]
+struct Direct<T>(T);
+
+/// A coefficient without a fast transform, so that slices of it are always multiplied with Karatsuba's algorithm above its threshold
+#[derive(Clone, Copy, Default)]
+struct Ring<T>(T);
+
+impl<T> Add for Direct<T>
+where
+    T: Add<Output = T>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output
+    {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<T> Mul for Direct<T>
+where
+    T: Mul<Output = T>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output
+    {
+        Self(self.0*rhs.0)
+    }
+}
+
+impl<T> Add for Ring<T>
+where
+    T: Add<Output = T>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output
+    {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<T> Sub for Ring<T>
+where
+    T: Sub<Output = T>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output
+    {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<T> Mul for Ring<T>
+where
+    T: Mul<Output = T>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output
+    {
+        Self(self.0*rhs.0)
+    }
+}
+
+fn coefficients<T>(n: usize, f: impl Fn(i64) -> T) -> Vec<T>
+{
+    (0..n).map(|k| f((k % 7) as i64 - 3)).collect()
+}
+
+/// Compares direct convolution, Karatsuba's algorithm, and a fast transform where there is one, against the choice made by [mul_polynomial](MulPolynomial::mul_polynomial)
+macro_rules! bench_mul_polynomial {
+    ($t:ty, $f:expr; $($n:literal: $mul:ident, $convolve:ident, $karatsuba:ident $(, $fft:ident)?);*) => {
+        $(
+            #[bench]
+            fn $mul(bencher: &mut Bencher)
+            {
+                let p: Vec<$t> = coefficients($n, $f);
+                bencher.iter(|| black_box(p.as_slice()).mul_polynomial(black_box(p.as_slice())));
+            }
+
+            #[bench]
+            fn $convolve(bencher: &mut Bencher)
+            {
+                let p = coefficients($n, |c| Direct(($f)(c)));
+                bencher.iter(|| black_box(p.as_slice()).mul_polynomial(black_box(p.as_slice())));
+            }
+
+            #[bench]
+            fn $karatsuba(bencher: &mut Bencher)
+            {
+                let p = coefficients($n, |c| Ring(($f)(c)));
+                bencher.iter(|| black_box(p.as_slice()).mul_polynomial(black_box(p.as_slice())));
+            }
+
+            $(
+                #[bench]
+                fn $fft(bencher: &mut Bencher)
+                {
+                    let p: Vec<$t> = coefficients($n, $f);
+                    bencher.iter(|| black_box(p.as_slice()).mul_polynomial_fft(black_box(p.as_slice())));
+                }
+            )?
+        )*
+    };
+}
+
+mod i64
+{
+    use super::*;
+
+    bench_mul_polynomial!(i64, |c: i64| c;
+        16: mul_16, convolve_16, karatsuba_16;
+        32: mul_32, convolve_32, karatsuba_32;
+        48: mul_48, convolve_48, karatsuba_48;
+        64: mul_64, convolve_64, karatsuba_64;
+        128: mul_128, convolve_128, karatsuba_128;
+        256: mul_256, convolve_256, karatsuba_256;
+        512: mul_512, convolve_512, karatsuba_512
+    );
+}
+
+mod f64
+{
+    use super::*;
+
+    bench_mul_polynomial!(f64, |c: i64| c as f64;
+        16: mul_16, convolve_16, karatsuba_16, fft_16;
+        32: mul_32, convolve_32, karatsuba_32, fft_32;
+        48: mul_48, convolve_48, karatsuba_48, fft_48;
+        64: mul_64, convolve_64, karatsuba_64, fft_64;
+        128: mul_128, convolve_128, karatsuba_128, fft_128;
+        192: mul_192, convolve_192, karatsuba_192, fft_192;
+        224: mul_224, convolve_224, karatsuba_224, fft_224;
+        256: mul_256, convolve_256, karatsuba_256, fft_256;
+        512: mul_512, convolve_512, karatsuba_512, fft_512;
+        1024: mul_1024, convolve_1024, karatsuba_1024, fft_1024
+    );
+}
+
+mod mod_int
+{
+    use super::*;
+
+    bench_mul_polynomial!(M, |c: i64| M::new(c.rem_euclid(NTT_PRIME as i64) as u64);
+        32: mul_32, convolve_32, karatsuba_32, fft_32;
+        48: mul_48, convolve_48, karatsuba_48, fft_48;
+        64: mul_64, convolve_64, karatsuba_64, fft_64;
+        128: mul_128, convolve_128, karatsuba_128, fft_128;
+        256: mul_256, convolve_256, karatsuba_256, fft_256;
+        1024: mul_1024, convolve_1024, karatsuba_1024, fft_1024
+    );
+}
@@ -0,0 +1,47 @@
+#![feature(test)]
+
+extern crate test;
+
+use polynomial_ops::*;
+use test::{black_box, Bencher};
+
+type M = ModInt<NTT_PRIME>;
+
+fn polynomial_and_points(n: usize) -> (Vec<M>, Vec<M>)
+{
+    let p = (0..n as u64).map(|k| M::new(k*k + 1)).collect();
+    let x = (0..n as u64).map(|k| M::new(3*k + 2)).collect();
+    (p, x)
+}
+
+/// Compares the choice made by [evaluate_many](MultipointPolynomial::evaluate_many) against Horner's method at every point, for as many coefficients as points
+macro_rules! bench_evaluate_many {
+    ($($n:literal: $evaluate_many:ident, $horner:ident);*) => {
+        $(
+            #[bench]
+            fn $evaluate_many(bencher: &mut Bencher)
+            {
+                let (p, x) = polynomial_and_points($n);
+                bencher.iter(|| black_box(p.as_slice()).evaluate_many(black_box(&x)));
+            }
+
+            #[bench]
+            fn $horner(bencher: &mut Bencher)
+            {
+                let (p, x) = polynomial_and_points($n);
+                bencher.iter(|| black_box(&x).iter()
+                    .map(|&x| black_box(p.as_slice()).evaluate_as_polynomial(x))
+                    .collect::<Vec<_>>()
+                );
+            }
+        )*
+    };
+}
+
+bench_evaluate_many!(
+    128: evaluate_many_128, horner_128;
+    256: evaluate_many_256, horner_256;
+    512: evaluate_many_512, horner_512;
+    1024: evaluate_many_1024, horner_1024;
+    4096: evaluate_many_4096, horner_4096
+);
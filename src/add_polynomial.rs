@@ -0,0 +1,185 @@
+use core::{alloc::Allocator, ops::Add};
+
+#[const_trait]
+pub trait AddPolynomial<Rhs>
+{
+    type Output;
+
+    /// Adds two polynomials, padding the shorter one with zeros
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// assert_eq!(
+    ///     [1.0, 1.0].add_polynomial([1.0, 2.0, 3.0]),
+    ///     [2.0, 3.0, 3.0]
+    /// );
+    /// assert_eq!(
+    ///     [1.0, -1.0, 2.0].add_polynomial([1.0]),
+    ///     [2.0, -1.0, 2.0]
+    /// );
+    /// ```
+    fn add_polynomial(self, rhs: Rhs) -> Self::Output;
+}
+
+pub const fn polynomial_sum_length(n: usize, m: usize) -> usize
+{
+    if n > m
+    {
+        return n;
+    }
+    m
+}
+
+impl<C1, C2, const N1: usize, const N2: usize> const AddPolynomial<[C2; N2]> for [C1; N1]
+where
+    C1: /*~const*/ Add<C2> + /*~const*/ Default + Copy,
+    C2: /*~const*/ Default + Copy,
+    <C1 as Add<C2>>::Output: /*~const*/ Default + Copy,
+    [(); polynomial_sum_length(N1, N2)]:
+{
+    type Output = [<C1 as Add<C2>>::Output; polynomial_sum_length(N1, N2)];
+
+    fn add_polynomial(self, rhs: [C2; N2]) -> Self::Output
+    {
+        let mut y = [Default::default(); polynomial_sum_length(N1, N2)];
+        let mut k = 0;
+        while k < polynomial_sum_length(N1, N2)
+        {
+            let a = if k < N1 {self[k]} else {Default::default()};
+            let b = if k < N2 {rhs[k]} else {Default::default()};
+
+            y[k] = a + b;
+
+            k += 1;
+        }
+        y
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C1, C2> AddPolynomial<&[C2]> for &[C1]
+where
+    C1: Add<C2> + Default + Copy,
+    C2: Default + Copy
+{
+    type Output = Vec<<C1 as Add<C2>>::Output>;
+
+    fn add_polynomial(self, rhs: &[C2]) -> Self::Output
+    {
+        let len = polynomial_sum_length(self.len(), rhs.len());
+        (0..len)
+            .map(|k| self.get(k).copied().unwrap_or_default() + rhs.get(k).copied().unwrap_or_default())
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C1, A1, C2, A2> AddPolynomial<Vec<C2, A2>> for Vec<C1, A1>
+where
+    A1: Allocator,
+    A2: Allocator,
+    C1: Add<C2> + Default + Copy,
+    C2: Default + Copy
+{
+    type Output = Vec<<C1 as Add<C2>>::Output>;
+
+    fn add_polynomial(self, rhs: Vec<C2, A2>) -> Self::Output
+    {
+        self.as_slice().add_polynomial(rhs.as_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C1, A1, C2> AddPolynomial<&[C2]> for Vec<C1, A1>
+where
+    A1: Allocator,
+    C1: Add<C2> + Default + Copy,
+    C2: Default + Copy
+{
+    type Output = Vec<<C1 as Add<C2>>::Output>;
+
+    fn add_polynomial(self, rhs: &[C2]) -> Self::Output
+    {
+        self.as_slice().add_polynomial(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C1, C2, A2> AddPolynomial<Vec<C2, A2>> for &[C1]
+where
+    A2: Allocator,
+    C1: Add<C2> + Default + Copy,
+    C2: Default + Copy
+{
+    type Output = Vec<<C1 as Add<C2>>::Output>;
+
+    fn add_polynomial(self, rhs: Vec<C2, A2>) -> Self::Output
+    {
+        self.add_polynomial(rhs.as_slice())
+    }
+}
+
+
+
+#[cfg(feature = "std")]
+impl<C1, C2, const N: usize> AddPolynomial<&[C2]> for [C1; N]
+where
+    C1: Add<C2> + Default + Copy,
+    C2: Default + Copy
+{
+    type Output = Vec<<C1 as Add<C2>>::Output>;
+
+    fn add_polynomial(self, rhs: &[C2]) -> Self::Output
+    {
+        self.as_slice().add_polynomial(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C1, C2, A, const N: usize> AddPolynomial<Vec<C2, A>> for [C1; N]
+where
+    A: Allocator,
+    C1: Add<C2> + Default + Copy,
+    C2: Default + Copy
+{
+    type Output = Vec<<C1 as Add<C2>>::Output>;
+
+    fn add_polynomial(self, rhs: Vec<C2, A>) -> Self::Output
+    {
+        self.as_slice().add_polynomial(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C1, C2, const N: usize> AddPolynomial<[C2; N]> for &[C1]
+where
+    C1: Add<C2> + Default + Copy,
+    C2: Default + Copy
+{
+    type Output = Vec<<C1 as Add<C2>>::Output>;
+
+    fn add_polynomial(self, rhs: [C2; N]) -> Self::Output
+    {
+        self.add_polynomial(rhs.as_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C1, C2, A, const N: usize> AddPolynomial<[C2; N]> for Vec<C1, A>
+where
+    A: Allocator,
+    C1: Add<C2> + Default + Copy,
+    C2: Default + Copy
+{
+    type Output = Vec<<C1 as Add<C2>>::Output>;
+
+    fn add_polynomial(self, rhs: [C2; N]) -> Self::Output
+    {
+        self.add_polynomial(rhs.as_slice())
+    }
+}
@@ -0,0 +1,158 @@
+use core::alloc::Allocator;
+
+use num_traits::Float;
+
+pub trait CompensatedPolynomial<F>
+{
+    /// Evaluates a polynomial using the compensated Horner scheme
+    /// 
+    /// The rounding errors of every product and sum in Horner's method are found exactly with the error-free transformations TwoProduct, using a fused multiply-add,
+    /// and TwoSum, and are themselves accumulated with Horner's method as a correction to the result. The result is as accurate as if Horner's method was computed
+    /// with twice the working precision and then rounded, which gives trustworthy values even close to multiple roots, where plain Horner's method is dominated by rounding errors.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 1)^7, close to its root of multiplicity seven
+    /// let p = [-1.0, 7.0, -21.0, 35.0, -35.0, 21.0, -7.0, 1.0];
+    /// let x = 1.001f64;
+    /// let exact = (x - 1.0).powi(7);
+    /// 
+    /// assert!((p.evaluate_compensated(x) - exact).abs() < 1e-6*exact);
+    /// assert!((p.evaluate_as_polynomial(x) - exact).abs() > exact);
+    /// ```
+    fn evaluate_compensated(self, x: F) -> F;
+
+    /// Evaluates a polynomial using the compensated Horner scheme, and yields the value along with an a posteriori bound on its absolute error
+    /// 
+    /// The bound is computed in the working precision from the magnitudes of the rounding errors, following Langlois and Louvet,
+    /// and holds as long as no underflow or overflow occurs.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 1)^7
+    /// let p = [-1.0, 7.0, -21.0, 35.0, -35.0, 21.0, -7.0, 1.0];
+    /// let x = 1.001f64;
+    /// let exact = (x - 1.0).powi(7);
+    /// 
+    /// let (y, bound) = p.evaluate_compensated_with_error_bound(x);
+    /// assert!((y - exact).abs() <= bound);
+    /// assert!(bound < 1e-6*exact);
+    /// ```
+    fn evaluate_compensated_with_error_bound(self, x: F) -> (F, F);
+}
+
+/// The exact sum `a + b = s + e`, where `s` is the rounded sum
+pub(crate) fn two_sum<F>(a: F, b: F) -> (F, F)
+where
+    F: Float
+{
+    let s = a + b;
+    let z = s - a;
+    (s, (a - (s - z)) + (b - z))
+}
+
+/// The exact product `a*b = p + e`, where `p` is the rounded product
+pub(crate) fn two_product<F>(a: F, b: F) -> (F, F)
+where
+    F: Float
+{
+    let p = a*b;
+    (p, a.mul_add(b, -p))
+}
+
+/// The compensated Horner scheme, yielding the result and the rounding errors evaluated with absolute values at `|x|`
+fn compensated_horner<F>(polynomial: &[F], x: F) -> (F, F)
+where
+    F: Float
+{
+    let Some((&lead, rest)) = polynomial.split_last()
+    else
+    {
+        return (F::zero(), F::zero())
+    };
+
+    let abs_x = x.abs();
+    let mut s = lead;
+    let mut c = F::zero();
+    let mut e = F::zero();
+    for &a in rest.iter()
+        .rev()
+    {
+        let (p, pi) = two_product(s, x);
+        let sigma;
+        (s, sigma) = two_sum(p, a);
+        c = c*x + (pi + sigma);
+        e = e*abs_x + (pi.abs() + sigma.abs());
+    }
+    (s + c, e)
+}
+
+fn evaluate_compensated_with_error_bound<F>(polynomial: &[F], x: F) -> (F, F)
+where
+    F: Float
+{
+    let (r, e) = compensated_horner(polynomial, x);
+    let n = F::from(polynomial.len().saturating_sub(1)).unwrap();
+
+    let one = F::one();
+    let two = one + one;
+    let u = F::epsilon()/two;
+    let gamma = |k: F| k*u/(one - k*u);
+
+    let r_abs = r.abs();
+    let alpha = gamma(F::from(4).unwrap()*n + two)*e + two*u*u*r_abs;
+    (r, (u*r_abs + alpha)/(one - two*(n + one)*u))
+}
+
+impl<F> CompensatedPolynomial<F> for &[F]
+where
+    F: Float
+{
+    fn evaluate_compensated(self, x: F) -> F
+    {
+        compensated_horner(self, x).0
+    }
+
+    fn evaluate_compensated_with_error_bound(self, x: F) -> (F, F)
+    {
+        evaluate_compensated_with_error_bound(self, x)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, A> CompensatedPolynomial<F> for Vec<F, A>
+where
+    A: Allocator,
+    F: Float
+{
+    fn evaluate_compensated(self, x: F) -> F
+    {
+        self.as_slice().evaluate_compensated(x)
+    }
+
+    fn evaluate_compensated_with_error_bound(self, x: F) -> (F, F)
+    {
+        self.as_slice().evaluate_compensated_with_error_bound(x)
+    }
+}
+
+impl<F, const N: usize> CompensatedPolynomial<F> for [F; N]
+where
+    F: Float
+{
+    fn evaluate_compensated(self, x: F) -> F
+    {
+        self.as_slice().evaluate_compensated(x)
+    }
+
+    fn evaluate_compensated_with_error_bound(self, x: F) -> (F, F)
+    {
+        self.as_slice().evaluate_compensated_with_error_bound(x)
+    }
+}
@@ -0,0 +1,205 @@
+use core::{alloc::Allocator, ops::{Add, AddAssign, Mul}};
+
+use super::*;
+
+#[const_trait]
+pub trait ComposePolynomial<Rhs>
+{
+    type Output;
+
+    /// Composes two polynomials, and yields the polynomial `p(q(x))`, where `p` is `self` and `q` is `rhs`
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// // p(x) = x^2 + 1, q(x) = 2x - 1
+    /// const P: [i32; 3] = [1, 0, 1];
+    /// const Q: [i32; 2] = [-1, 2];
+    /// 
+    /// // p(q(x)) = (2x - 1)^2 + 1 = 4x^2 - 4x + 2
+    /// let pq = P.compose_polynomial(Q);
+    /// assert_eq!(pq, [2, -4, 4]);
+    /// for x in -16..16
+    /// {
+    ///     assert_eq!(
+    ///         pq.evaluate_as_polynomial(x),
+    ///         P.evaluate_as_polynomial(Q.evaluate_as_polynomial(x))
+    ///     );
+    /// }
+    /// ```
+    fn compose_polynomial(self, rhs: Rhs) -> Self::Output;
+}
+
+pub const fn polynomial_composition_length(n: usize, m: usize) -> usize
+{
+    if n == 0
+    {
+        return 0;
+    }
+    if m == 0
+    {
+        return 1;
+    }
+    (n - 1)*(m - 1) + 1
+}
+
+impl<C, const N1: usize, const N2: usize> const ComposePolynomial<[C; N2]> for [C; N1]
+where
+    C: /*~const*/ Mul<C, Output = C> + /*~const*/ AddAssign<C> + /*~const*/ Default + Copy,
+    [(); polynomial_composition_length(N1, N2)]:
+{
+    type Output = [C; polynomial_composition_length(N1, N2)];
+
+    fn compose_polynomial(self, rhs: [C; N2]) -> Self::Output
+    {
+        let mut y = [Default::default(); polynomial_composition_length(N1, N2)];
+
+        // Horner's method, where the accumulator of degree `d` only occupies the first `d + 1` coefficients
+        let mut y_len = 0;
+        let mut i = N1;
+        while i > 0
+        {
+            i -= 1;
+
+            let yq_len = polynomial_product_length(y_len, N2);
+            let mut yq = [Default::default(); polynomial_composition_length(N1, N2)];
+            let mut k = 0;
+            while k < yq_len
+            {
+                let mut j = k.saturating_sub(N2 - 1);
+                while j <= k && j < y_len
+                {
+                    yq[k] += y[j]*rhs[k - j];
+                    j += 1;
+                }
+                k += 1;
+            }
+
+            yq[0] += self[i];
+            y = yq;
+            y_len = yq_len.max(1);
+        }
+        y
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> ComposePolynomial<&[C]> for &[C]
+where
+    C: Mul<C, Output = C> + Add<C, Output = C> + Default + Copy
+{
+    type Output = Vec<C>;
+
+    fn compose_polynomial(self, rhs: &[C]) -> Self::Output
+    {
+        self.iter()
+            .rev()
+            .fold(vec![], |y, &c| {
+                let yq = if y.is_empty() {y} else {y.as_slice().mul_polynomial(rhs)};
+                yq.as_slice().add_polynomial([c].as_slice())
+            })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A1, A2> ComposePolynomial<Vec<C, A2>> for Vec<C, A1>
+where
+    A1: Allocator,
+    A2: Allocator,
+    C: Mul<C, Output = C> + Add<C, Output = C> + Default + Copy
+{
+    type Output = Vec<C>;
+
+    fn compose_polynomial(self, rhs: Vec<C, A2>) -> Self::Output
+    {
+        self.as_slice().compose_polynomial(rhs.as_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A1> ComposePolynomial<&[C]> for Vec<C, A1>
+where
+    A1: Allocator,
+    C: Mul<C, Output = C> + Add<C, Output = C> + Default + Copy
+{
+    type Output = Vec<C>;
+
+    fn compose_polynomial(self, rhs: &[C]) -> Self::Output
+    {
+        self.as_slice().compose_polynomial(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A2> ComposePolynomial<Vec<C, A2>> for &[C]
+where
+    A2: Allocator,
+    C: Mul<C, Output = C> + Add<C, Output = C> + Default + Copy
+{
+    type Output = Vec<C>;
+
+    fn compose_polynomial(self, rhs: Vec<C, A2>) -> Self::Output
+    {
+        self.compose_polynomial(rhs.as_slice())
+    }
+}
+
+
+
+#[cfg(feature = "std")]
+impl<C, const N: usize> ComposePolynomial<&[C]> for [C; N]
+where
+    C: Mul<C, Output = C> + Add<C, Output = C> + Default + Copy
+{
+    type Output = Vec<C>;
+
+    fn compose_polynomial(self, rhs: &[C]) -> Self::Output
+    {
+        self.as_slice().compose_polynomial(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A, const N: usize> ComposePolynomial<Vec<C, A>> for [C; N]
+where
+    A: Allocator,
+    C: Mul<C, Output = C> + Add<C, Output = C> + Default + Copy
+{
+    type Output = Vec<C>;
+
+    fn compose_polynomial(self, rhs: Vec<C, A>) -> Self::Output
+    {
+        self.as_slice().compose_polynomial(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, const N: usize> ComposePolynomial<[C; N]> for &[C]
+where
+    C: Mul<C, Output = C> + Add<C, Output = C> + Default + Copy
+{
+    type Output = Vec<C>;
+
+    fn compose_polynomial(self, rhs: [C; N]) -> Self::Output
+    {
+        self.compose_polynomial(rhs.as_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A, const N: usize> ComposePolynomial<[C; N]> for Vec<C, A>
+where
+    A: Allocator,
+    C: Mul<C, Output = C> + Add<C, Output = C> + Default + Copy
+{
+    type Output = Vec<C>;
+
+    fn compose_polynomial(self, rhs: [C; N]) -> Self::Output
+    {
+        self.compose_polynomial(rhs.as_slice())
+    }
+}
@@ -0,0 +1,80 @@
+use core::{alloc::Allocator, ops::{Add, Mul}};
+
+use array__ops::ArrayOps;
+use num_traits::{One, Zero};
+
+#[const_trait]
+pub trait DerivePolynomial
+{
+    type Output;
+
+    /// Differentiates a polynomial, and yields a polynomial one coefficient shorter
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// // d/dx (1 + 2x + 3x^2 + 4x^3) = 2 + 6x + 12x^2
+    /// assert_eq!(
+    ///     [1, 2, 3, 4].derive_polynomial(),
+    ///     [2, 6, 12]
+    /// );
+    /// assert_eq!(
+    ///     vec![1.0, 2.0, 3.0].derive_polynomial(),
+    ///     vec![2.0, 6.0]
+    /// );
+    /// ```
+    fn derive_polynomial(self) -> Self::Output;
+}
+
+impl<C, const N: usize> /*const*/ DerivePolynomial for [C; N]
+where
+    C: /*~const*/ Zero + /*~const*/ One + /*~const*/ Add<Output = C> + /*~const*/ Mul<Output = C> + Copy,
+    [(); N - 1]:
+{
+    type Output = [C; N - 1];
+
+    fn derive_polynomial(self) -> Self::Output
+    {
+        let mut n = C::zero();
+        ArrayOps::fill(/*const*/ |k| {
+            n = n + C::one();
+            self[k + 1]*n
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> DerivePolynomial for &[C]
+where
+    C: Zero + One + Add<Output = C> + Mul<Output = C> + Copy
+{
+    type Output = Vec<C>;
+
+    fn derive_polynomial(self) -> Self::Output
+    {
+        self.iter()
+            .skip(1)
+            .scan(C::zero(), |n, &c| {
+                *n = *n + C::one();
+                Some(c*(*n))
+            }).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A> DerivePolynomial for Vec<C, A>
+where
+    A: Allocator,
+    C: Zero + One + Add<Output = C> + Mul<Output = C> + Copy
+{
+    type Output = Vec<C>;
+
+    fn derive_polynomial(self) -> Self::Output
+    {
+        self.as_slice().derive_polynomial()
+    }
+}
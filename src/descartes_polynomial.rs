@@ -0,0 +1,285 @@
+use core::alloc::Allocator;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+use super::*;
+
+pub trait DescartesPolynomial
+{
+    /// Counts the sign changes in the coefficients, which by Descartes' rule of signs bounds the number of positive roots
+    /// 
+    /// The number of positive roots, counted with multiplicity, is the number of sign changes minus an even number.
+    /// Zero coefficients are skipped.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 1)(x - 2)(x + 3) = x^3 - 7x + 6
+    /// assert_eq!([6, -7, 0, 1].sign_variations(), 2);
+    /// ```
+    fn sign_variations(self) -> usize;
+
+    /// Isolates the distinct real roots of an integer polynomial exactly, using Descartes' rule of signs and the Vincent–Collins–Akritas bisection
+    /// 
+    /// The polynomial is first made square-free, by dividing out its greatest common divisor with its derivative, found modulo a large prime when its coefficients are small enough, and otherwise with big integers. The positive roots in `(0, 1)` are then isolated by repeatedly halving the interval,
+    /// using Taylor shifts to count sign variations on each half, until every half has zero or one sign variation.
+    /// Roots in `(1, ∞)` are isolated the same way on the reversed polynomial, and negative roots on `p(-x)`.
+    /// 
+    /// Each root is given as a pair of rationals `(a, b)`, which is either an exact rational root when `a == b`,
+    /// or otherwise an open interval `(a, b)` holding exactly one root. The intervals are disjoint and sorted in ascending order.
+    /// 
+    /// Every bisection grows the coefficients by about one bit per degree, so the Taylor shifts are done with big integers, and the endpoints are big rationals,
+    /// which can not overflow however high the degree or close the roots are.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use num_rational::BigRational;
+    /// use num_traits::Zero;
+    /// use polynomial_ops::*;
+    /// 
+    /// let q = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+    /// 
+    /// // x^3 - 2x = x(x^2 - 2)
+    /// let roots = [0, -2, 0, 1].isolate_real_roots_exact();
+    /// 
+    /// assert_eq!(roots.len(), 3);
+    /// assert!(roots[0].0 < q(-141, 100) && q(-142, 100) < roots[0].1);
+    /// assert_eq!(roots[1], (BigRational::zero(), BigRational::zero()));
+    /// assert!(roots[2].0 < q(142, 100) && q(141, 100) < roots[2].1);
+    /// ```
+    fn isolate_real_roots_exact(self) -> Vec<(BigRational, BigRational)>;
+}
+
+fn sign_variations<C>(polynomial: &[C]) -> usize
+where
+    C: Signed
+{
+    polynomial.iter()
+        .filter(|c| !c.is_zero())
+        .zip(polynomial.iter()
+            .filter(|c| !c.is_zero())
+            .skip(1)
+        ).filter(|(a, b)| a.is_positive() != b.is_positive())
+        .count()
+}
+
+fn derive_big_polynomial(polynomial: &[BigInt]) -> Vec<BigInt>
+{
+    polynomial.iter()
+        .enumerate()
+        .skip(1)
+        .map(|(k, c)| c*BigInt::from(k))
+        .collect()
+}
+
+/// The square-free part of a primitive polynomial of positive degree, which is found modulo a large prime if possible, and otherwise with big integers
+fn square_free_part(polynomial: &[i128]) -> Vec<BigInt>
+{
+    let big = |p: &[i128]| p.iter()
+        .map(|&c| BigInt::from(c))
+        .collect::<Vec<_>>();
+
+    let modular = polynomial.iter()
+        .enumerate()
+        .skip(1)
+        .map(|(k, &c)| c.checked_mul(k as i128))
+        .collect::<Option<Vec<_>>>()
+        .and_then(|dp| modular_gcd_polynomial(polynomial, &dp))
+        .and_then(|g| if g.len() <= 1
+        {
+            Some(polynomial.to_vec())
+        }
+        else
+        {
+            exact_div_polynomial(polynomial, &g)
+        });
+    if let Some(p) = modular
+    {
+        return primitive_big_polynomial(big(&p))
+    }
+
+    let p = big(polynomial);
+    let g = gcd_big_polynomial(&p, &derive_big_polynomial(&p));
+    primitive_big_polynomial(exact_div_big_polynomial(&p, &g).unwrap_or_else(|| unreachable!()))
+}
+
+/// Computes `p(x + 1)`
+fn taylor_shift_one(mut polynomial: Vec<BigInt>) -> Vec<BigInt>
+{
+    let n = polynomial.len();
+    for i in 0..n.saturating_sub(1)
+    {
+        for j in (i..n - 1).rev()
+        {
+            let c = polynomial[j + 1].clone();
+            polynomial[j] += c;
+        }
+    }
+    polynomial
+}
+
+/// Computes `2^n p(x/2)`, where `n` is the degree
+fn halve(mut polynomial: Vec<BigInt>) -> Vec<BigInt>
+{
+    let n = polynomial.len();
+    for (k, c) in polynomial.iter_mut()
+        .enumerate()
+    {
+        *c <<= n - 1 - k;
+    }
+    primitive_big_polynomial(polynomial)
+}
+
+/// Bounds the number of roots in `(0, 1)` by the sign variations of `(x + 1)^n p(1/(x + 1))`
+fn unit_sign_variations(polynomial: &[BigInt]) -> usize
+{
+    let reversed = polynomial.iter()
+        .rev()
+        .cloned()
+        .collect();
+    sign_variations(&taylor_shift_one(reversed))
+}
+
+/// Isolates the roots in `(0, 1)` of a square-free polynomial, where `(lo, hi)` is the interval mapped to `(0, 1)`
+fn isolate_unit_roots(polynomial: Vec<BigInt>, lo: BigRational, hi: BigRational, roots: &mut Vec<(BigRational, BigRational)>)
+{
+    match unit_sign_variations(&polynomial)
+    {
+        0 => return,
+        1 => return roots.push((lo, hi)),
+        _ => ()
+    }
+
+    let mid = (&lo + &hi)/BigInt::from(2);
+    let left = halve(polynomial);
+    let mut right = primitive_big_polynomial(taylor_shift_one(left.clone()));
+
+    isolate_unit_roots(left, lo, mid.clone(), roots);
+    if right[0].is_zero()
+    {
+        roots.push((mid.clone(), mid.clone()));
+        right.remove(0);
+    }
+    isolate_unit_roots(right, mid, hi, roots);
+}
+
+/// Isolates the positive roots of a square-free polynomial with a nonzero constant term
+fn isolate_positive_roots(polynomial: &[BigInt]) -> Vec<(BigRational, BigRational)>
+{
+    let zero = BigRational::zero();
+    let one = BigRational::one();
+    let mut roots = vec![];
+    isolate_unit_roots(polynomial.to_vec(), zero.clone(), one.clone(), &mut roots);
+    if polynomial.iter().sum::<BigInt>().is_zero()
+    {
+        roots.push((one.clone(), one.clone()));
+    }
+
+    // The roots in (1, ∞) are the reciprocals of the roots of the reversed polynomial in (0, 1), and are below Cauchy's bound
+    let lead = polynomial[polynomial.len() - 1].abs();
+    let max = polynomial.iter()
+        .map(Signed::abs)
+        .max()
+        .unwrap_or_default();
+    let bound = BigRational::new(max + &lead, lead);
+    let mut reciprocal = vec![];
+    let reversed = polynomial.iter()
+        .rev()
+        .cloned()
+        .collect();
+    isolate_unit_roots(reversed, zero.clone(), one, &mut reciprocal);
+    roots.extend(reciprocal.into_iter()
+        .rev()
+        .map(|(a, b)| (
+            b.recip(),
+            if a == zero {bound.clone()} else {a.recip()}
+        ))
+    );
+    roots
+}
+
+fn isolate_real_roots_exact(polynomial: &[i128]) -> Vec<(BigRational, BigRational)>
+{
+    let p = primitive_polynomial(trim_polynomial(polynomial.to_vec()));
+    if p.len() <= 1
+    {
+        return vec![]
+    }
+    let mut p = square_free_part(&p);
+
+    let mut roots = vec![];
+    if p[0].is_zero()
+    {
+        roots.push((BigRational::zero(), BigRational::zero()));
+        p.remove(0);
+    }
+
+    let negated: Vec<BigInt> = p.iter()
+        .enumerate()
+        .map(|(k, c)| if k % 2 == 1 {-c} else {c.clone()})
+        .collect();
+    roots.extend(isolate_positive_roots(&negated)
+        .into_iter()
+        .map(|(a, b)| (-b, -a))
+    );
+    roots.extend(isolate_positive_roots(&p));
+    roots.sort_by(|(a, _), (b, _)| a.cmp(b));
+    roots
+}
+
+impl<C> DescartesPolynomial for &[C]
+where
+    C: Into<i128> + Copy
+{
+    fn sign_variations(self) -> usize
+    {
+        sign_variations(&self.iter()
+            .map(|&c| c.into())
+            .collect::<Vec<i128>>()
+        )
+    }
+
+    fn isolate_real_roots_exact(self) -> Vec<(BigRational, BigRational)>
+    {
+        isolate_real_roots_exact(&self.iter()
+            .map(|&c| c.into())
+            .collect::<Vec<_>>()
+        )
+    }
+}
+
+impl<C, A> DescartesPolynomial for Vec<C, A>
+where
+    A: Allocator,
+    C: Into<i128> + Copy
+{
+    fn sign_variations(self) -> usize
+    {
+        self.as_slice().sign_variations()
+    }
+
+    fn isolate_real_roots_exact(self) -> Vec<(BigRational, BigRational)>
+    {
+        self.as_slice().isolate_real_roots_exact()
+    }
+}
+
+impl<C, const N: usize> DescartesPolynomial for [C; N]
+where
+    C: Into<i128> + Copy
+{
+    fn sign_variations(self) -> usize
+    {
+        self.as_slice().sign_variations()
+    }
+
+    fn isolate_real_roots_exact(self) -> Vec<(BigRational, BigRational)>
+    {
+        self.as_slice().isolate_real_roots_exact()
+    }
+}
@@ -0,0 +1,253 @@
+use core::{alloc::Allocator, fmt::Display, ops::{Div, Mul, Sub}};
+
+use array__ops::ArrayOps;
+use num_traits::Zero;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DivRemPolynomialError
+{
+    /// The divisor's leading coefficient is zero, or the divisor is the zero polynomial
+    ZeroLeadingCoefficient
+}
+
+impl Display for DivRemPolynomialError
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        match self
+        {
+            Self::ZeroLeadingCoefficient => write!(f, "polynomial division by a zero leading coefficient")
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DivRemPolynomialError
+{
+
+}
+
+#[const_trait]
+pub trait DivRemPolynomial<Rhs>
+{
+    type Quotient;
+    type Remainder;
+
+    /// Divides one polynomial by another using long division, and yields the quotient and the remainder
+    /// 
+    /// The coefficients are expected to form a field (floating point numbers, rationals). For integers, the result is only exact if every division along the way is.
+    /// 
+    /// For arrays, the divisor's last coefficient is its leading coefficient, and the quotient and remainder have lengths `N1 - N2 + 1` and `N2 - 1`.
+    /// For slices and vectors, trailing zeros of the divisor are ignored, and the remainder has the length of the trimmed divisor minus one.
+    /// 
+    /// # Errors
+    /// 
+    /// Yields [DivRemPolynomialError::ZeroLeadingCoefficient](DivRemPolynomialError::ZeroLeadingCoefficient) if the divisor's leading coefficient is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x^2 - 1)/(x - 1) = x + 1
+    /// assert_eq!(
+    ///     [-1.0, 0.0, 1.0].div_rem_polynomial([-1.0, 1.0]),
+    ///     Ok(([1.0, 1.0], [0.0]))
+    /// );
+    /// // (3x^2 + 2x + 1)/(x + 1) = 3x - 1, remainder 2
+    /// assert_eq!(
+    ///     [1.0, 2.0, 3.0].div_rem_polynomial([1.0, 1.0]),
+    ///     Ok(([-1.0, 3.0], [2.0]))
+    /// );
+    /// assert_eq!(
+    ///     [1.0, 2.0].div_rem_polynomial([1.0, 0.0]),
+    ///     Err(DivRemPolynomialError::ZeroLeadingCoefficient)
+    /// );
+    /// ```
+    fn div_rem_polynomial(self, rhs: Rhs) -> Result<(Self::Quotient, Self::Remainder), DivRemPolynomialError>;
+}
+
+impl<C, const N1: usize, const N2: usize> const DivRemPolynomial<[C; N2]> for [C; N1]
+where
+    C: /*~const*/ Zero + /*~const*/ Sub<Output = C> + /*~const*/ Mul<Output = C> + /*~const*/ Div<Output = C> + Copy,
+    [(); N1 - N2 + 1]:,
+    [(); N2 - 1]:
+{
+    type Quotient = [C; N1 - N2 + 1];
+    type Remainder = [C; N2 - 1];
+
+    fn div_rem_polynomial(self, rhs: [C; N2]) -> Result<(Self::Quotient, Self::Remainder), DivRemPolynomialError>
+    {
+        let lead = rhs[N2 - 1];
+        if lead.is_zero()
+        {
+            return Err(DivRemPolynomialError::ZeroLeadingCoefficient)
+        }
+
+        let mut r = self;
+        let mut q = [C::zero(); N1 - N2 + 1];
+        let mut k = N1 - N2 + 1;
+        while k > 0
+        {
+            k -= 1;
+
+            let qk = r[k + N2 - 1]/lead;
+            q[k] = qk;
+
+            let mut j = 0;
+            while j < N2
+            {
+                r[k + j] = r[k + j] - qk*rhs[j];
+                j += 1;
+            }
+        }
+
+        Ok((q, ArrayOps::fill(/*const*/ |i| r[i])))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> DivRemPolynomial<&[C]> for &[C]
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Quotient = Vec<C>;
+    type Remainder = Vec<C>;
+
+    fn div_rem_polynomial(self, rhs: &[C]) -> Result<(Self::Quotient, Self::Remainder), DivRemPolynomialError>
+    {
+        let rhs_len = rhs.iter()
+            .rposition(|c| !c.is_zero())
+            .ok_or(DivRemPolynomialError::ZeroLeadingCoefficient)? + 1;
+        let lead = rhs[rhs_len - 1];
+
+        let self_len = self.len();
+        let mut r = self.to_vec();
+        r.resize(self_len.max(rhs_len - 1), C::zero());
+
+        let len = (self_len + 1).saturating_sub(rhs_len);
+        let mut q = vec![C::zero(); len];
+        for k in (0..len).rev()
+        {
+            let qk = r[k + rhs_len - 1]/lead;
+            q[k] = qk;
+
+            for (r, &c) in r[k..k + rhs_len].iter_mut()
+                .zip(rhs[..rhs_len].iter())
+            {
+                *r = *r - qk*c;
+            }
+        }
+        r.truncate(rhs_len - 1);
+
+        Ok((q, r))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A1, A2> DivRemPolynomial<Vec<C, A2>> for Vec<C, A1>
+where
+    A1: Allocator,
+    A2: Allocator,
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Quotient = Vec<C>;
+    type Remainder = Vec<C>;
+
+    fn div_rem_polynomial(self, rhs: Vec<C, A2>) -> Result<(Self::Quotient, Self::Remainder), DivRemPolynomialError>
+    {
+        self.as_slice().div_rem_polynomial(rhs.as_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A1> DivRemPolynomial<&[C]> for Vec<C, A1>
+where
+    A1: Allocator,
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Quotient = Vec<C>;
+    type Remainder = Vec<C>;
+
+    fn div_rem_polynomial(self, rhs: &[C]) -> Result<(Self::Quotient, Self::Remainder), DivRemPolynomialError>
+    {
+        self.as_slice().div_rem_polynomial(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A2> DivRemPolynomial<Vec<C, A2>> for &[C]
+where
+    A2: Allocator,
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Quotient = Vec<C>;
+    type Remainder = Vec<C>;
+
+    fn div_rem_polynomial(self, rhs: Vec<C, A2>) -> Result<(Self::Quotient, Self::Remainder), DivRemPolynomialError>
+    {
+        self.div_rem_polynomial(rhs.as_slice())
+    }
+}
+
+
+
+#[cfg(feature = "std")]
+impl<C, const N: usize> DivRemPolynomial<&[C]> for [C; N]
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Quotient = Vec<C>;
+    type Remainder = Vec<C>;
+
+    fn div_rem_polynomial(self, rhs: &[C]) -> Result<(Self::Quotient, Self::Remainder), DivRemPolynomialError>
+    {
+        self.as_slice().div_rem_polynomial(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A, const N: usize> DivRemPolynomial<Vec<C, A>> for [C; N]
+where
+    A: Allocator,
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Quotient = Vec<C>;
+    type Remainder = Vec<C>;
+
+    fn div_rem_polynomial(self, rhs: Vec<C, A>) -> Result<(Self::Quotient, Self::Remainder), DivRemPolynomialError>
+    {
+        self.as_slice().div_rem_polynomial(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, const N: usize> DivRemPolynomial<[C; N]> for &[C]
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Quotient = Vec<C>;
+    type Remainder = Vec<C>;
+
+    fn div_rem_polynomial(self, rhs: [C; N]) -> Result<(Self::Quotient, Self::Remainder), DivRemPolynomialError>
+    {
+        self.div_rem_polynomial(rhs.as_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A, const N: usize> DivRemPolynomial<[C; N]> for Vec<C, A>
+where
+    A: Allocator,
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Quotient = Vec<C>;
+    type Remainder = Vec<C>;
+
+    fn div_rem_polynomial(self, rhs: [C; N]) -> Result<(Self::Quotient, Self::Remainder), DivRemPolynomialError>
+    {
+        self.div_rem_polynomial(rhs.as_slice())
+    }
+}
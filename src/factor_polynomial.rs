@@ -0,0 +1,386 @@
+use core::alloc::Allocator;
+
+use num_bigint::BigInt;
+use num_traits::{ToPrimitive, Zero};
+
+use super::*;
+
+/// How many suitable primes to try before factoring modulo the one giving the fewest factors
+const FACTOR_PRIME_CANDIDATES: usize = 5;
+
+pub trait FactorPolynomial
+{
+    /// Factors an integer polynomial into irreducible polynomials over the integers, using the Zassenhaus algorithm
+    /// 
+    /// The polynomial is first split into square-free factors with Yun's algorithm. Each of these is factored modulo a small prime,
+    /// by distinct-degree factorization followed by Cantor–Zassenhaus equal-degree factorization, and the modular factors are Hensel lifted
+    /// modulo a power of the prime exceeding twice Mignotte's bound on the coefficients of any factor. The true factors are then found by recombining subsets of the lifted factors.
+    /// 
+    /// Yields the content, signed so that it has the sign of the leading coefficient, along with every irreducible factor and its multiplicity.
+    /// The factors are primitive with positive leading coefficients, and sorted by degree, and then by coefficients.
+    /// The zero polynomial has a content of zero and no factors.
+    /// 
+    /// The modular factorization is done with machine integers, while the lifting and the recombination use big integers,
+    /// since the prime power can be far beyond `2^64` for high degrees, so the factorization is always complete.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // The Chebyshev polynomial of the first kind T6(x) = 32x^6 - 48x^4 + 18x^2 - 1 = (2x^2 - 1)(16x^4 - 16x^2 + 1)
+    /// assert_eq!(
+    ///     [-1, 0, 18, 0, -48, 0, 32].factor_polynomial(),
+    ///     (1, vec![(vec![-1, 0, 2], 1), (vec![1, 0, -16, 0, 16], 1)])
+    /// );
+    /// 
+    /// // -6(x - 1)^2(x^2 + 1) = -6x^4 + 12x^3 - 12x^2 + 12x - 6
+    /// assert_eq!(
+    ///     vec![-6, 12, -12, 12, -6].factor_polynomial(),
+    ///     (-6, vec![(vec![-1, 1], 2), (vec![1, 0, 1], 1)])
+    /// );
+    /// ```
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if a factor has a coefficient that does not fit in an `i128`, which Mignotte's bound allows even when the coefficients of the polynomial fit.
+    fn factor_polynomial(self) -> (i128, Vec<(Vec<i128>, usize)>);
+}
+
+fn is_prime(n: u64) -> bool
+{
+    n >= 2 && (2..)
+        .take_while(|d| d*d <= n)
+        .all(|d| !n.is_multiple_of(d))
+}
+
+fn next_random(state: &mut u64) -> u64
+{
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+/// Splits a monic square-free polynomial modulo a prime into products of irreducible factors of equal degree, along with that degree
+fn distinct_degree_factorization_mod(polynomial: &[u64], p: u64) -> Vec<(Vec<u64>, usize)>
+{
+    let x = [0, 1];
+    let mut f = polynomial.to_vec();
+    let mut h = x.to_vec();
+    let mut factors = vec![];
+    let mut d = 0;
+    while f.len() > 1
+    {
+        d += 1;
+        if 2*d > f.len() - 1
+        {
+            let n = f.len() - 1;
+            factors.push((f, n));
+            break
+        }
+
+        // x^(p^d) - x is the product of every monic irreducible polynomial of a degree dividing d
+        h = pow_polynomial_mod(&h, p, &f, p);
+        let g = gcd_polynomial_mod(&sub_polynomial_mod(&h, &x, p), &f, p);
+        if g.len() > 1
+        {
+            f = div_rem_polynomial_mod(&f, &g, p).0;
+            h = div_rem_polynomial_mod(&h, &f, p).1;
+            factors.push((g, d));
+        }
+    }
+    factors
+}
+
+/// Splits a monic product of irreducible factors of degree `d` modulo an odd prime into those factors, using the Cantor–Zassenhaus algorithm
+fn equal_degree_factorization_mod(polynomial: Vec<u64>, d: usize, p: u64, state: &mut u64) -> Vec<Vec<u64>>
+{
+    let n = polynomial.len() - 1;
+    if n == d
+    {
+        return vec![polynomial]
+    }
+    loop
+    {
+        let a = trim_polynomial((0..n).map(|_| next_random(state) % p).collect());
+        if a.len() <= 1
+        {
+            continue
+        }
+
+        // a^((p^d - 1)/2), where (p^d - 1)/2 = (1 + p + ... + p^(d - 1))(p - 1)/2
+        let mut t = a.clone();
+        let mut b = a;
+        for _ in 1..d
+        {
+            t = pow_polynomial_mod(&t, p, &polynomial, p);
+            b = div_rem_polynomial_mod(&mul_polynomial_mod(&b, &t, p), &polynomial, p).1;
+        }
+        let b = pow_polynomial_mod(&b, (p - 1)/2, &polynomial, p);
+
+        let u = gcd_polynomial_mod(&sub_polynomial_mod(&b, &[1], p), &polynomial, p);
+        if u.len() > 1 && u.len() < polynomial.len()
+        {
+            let v = div_rem_polynomial_mod(&polynomial, &u, p).0;
+            let mut factors = equal_degree_factorization_mod(u, d, p, state);
+            factors.extend(equal_degree_factorization_mod(v, d, p, state));
+            return factors
+        }
+    }
+}
+
+/// Lifts `target = g*h (mod p)`, where `g` is monic and `h` has the leading coefficient of `target`, to `target = g*h (mod p^k)`
+/// 
+/// The coefficients of `target` must be nonnegative.
+fn hensel_lift(target: &[BigInt], g_p: Vec<u64>, h_p: Vec<u64>, p: u64, k: u32) -> (Vec<BigInt>, Vec<BigInt>)
+{
+    let (_, s, t) = ext_gcd_polynomial_mod(&g_p, &h_p, p);
+    let lead = &target[target.len() - 1];
+    let big = |f: &[u64]| f.iter()
+        .map(|&c| BigInt::from(c))
+        .collect::<Vec<_>>();
+
+    let mut g = big(&g_p);
+    let mut h = big(&h_p);
+    let mut pj = BigInt::from(p);
+    for _ in 1..k
+    {
+        let m = &pj*p;
+        let n = h.len() - 1;
+        h[n] = lead % &m;
+
+        // The error is divisible by p^j, and is corrected by dg and dh such that g*dh + h*dg = e (mod p)
+        let gh = mul_big_polynomial_mod(&g, &h, &m);
+        let e: Vec<u64> = trim_polynomial(reduce_big_polynomial_mod(&(0..target.len().max(gh.len()))
+                .map(|i| target.get(i).cloned().unwrap_or_default() - gh.get(i).cloned().unwrap_or_default())
+                .collect::<Vec<_>>(), &m)
+            .into_iter()
+            .map(|c| (c/&pj % p).to_u64().unwrap_or_else(|| unreachable!()))
+            .collect());
+        let (q, dg) = div_rem_polynomial_mod(&mul_polynomial_mod(&t, &e, p), &g_p, p);
+        let dh = add_polynomial_mod(&mul_polynomial_mod(&s, &e, p), &mul_polynomial_mod(&q, &h_p, p), p);
+
+        let correct = |mut f: Vec<BigInt>, d: Vec<u64>| {
+            f.resize(f.len().max(d.len()), BigInt::zero());
+            for (f, d) in f.iter_mut()
+                .zip(d)
+            {
+                *f += &pj*d;
+            }
+            reduce_big_polynomial_mod(&f, &m)
+        };
+        g = correct(g, dg);
+        h = correct(h, dh);
+        pj = m;
+    }
+    (g, h)
+}
+
+/// Lifts the monic factors of `target` modulo `p` to monic factors modulo `p^k`, by splitting the factors in halves
+/// 
+/// The coefficients of `target` must be nonnegative.
+fn hensel_lift_factors(target: &[BigInt], factors: &[Vec<u64>], p: u64, k: u32) -> Vec<Vec<BigInt>>
+{
+    let m = BigInt::from(p).pow(k);
+    if factors.len() == 1
+    {
+        let inv = target[target.len() - 1].modinv(&m)
+            .unwrap_or_else(|| unreachable!());
+        return vec![reduce_big_polynomial_mod(&target.iter()
+            .map(|c| c*&inv)
+            .collect::<Vec<_>>(), &m)]
+    }
+
+    let (left, right) = factors.split_at(factors.len()/2);
+    let lead = (&target[target.len() - 1] % p).to_u64()
+        .unwrap_or_else(|| unreachable!());
+    let g = left.iter()
+        .fold(vec![1], |g, f| mul_polynomial_mod(&g, f, p));
+    let h = right.iter()
+        .fold(vec![lead], |h, f| mul_polynomial_mod(&h, f, p));
+
+    let (g, h) = hensel_lift(target, g, h, p, k);
+    let mut lifted = hensel_lift_factors(&g, left, p, k);
+    lifted.extend(hensel_lift_factors(&h, right, p, k));
+    lifted
+}
+
+/// Advances to the next subset of `n` indices of the same size in lexicographic order, returning false after the last one
+fn next_combination(subset: &mut [usize], n: usize) -> bool
+{
+    let s = subset.len();
+    let Some(i) = (0..s).rev()
+        .find(|&i| subset[i] < n - s + i)
+    else
+    {
+        return false
+    };
+    subset[i] += 1;
+    for j in i + 1..s
+    {
+        subset[j] = subset[j - 1] + 1;
+    }
+    true
+}
+
+/// Factors a primitive square-free polynomial with a positive leading coefficient into irreducible factors
+fn factor_square_free_polynomial(polynomial: Vec<i128>) -> Vec<Vec<i128>>
+{
+    let n = polynomial.len() - 1;
+    if n <= 1
+    {
+        return vec![polynomial]
+    }
+    let lead = polynomial[n];
+
+    // Factor modulo a prime keeping the polynomial square-free, preferring the fewest factors
+    let mut state = 0x2545f4914f6cdd1d;
+    let derivative = polynomial.as_slice().derive_polynomial();
+    let mut best: Option<(u64, Vec<Vec<u64>>)> = None;
+    for p in (3..).step_by(2)
+        .filter(|&p| is_prime(p) && lead % p as i128 != 0)
+        .filter(|&p| gcd_polynomial_mod(&reduce_polynomial_mod(&polynomial, p), &reduce_polynomial_mod(&derivative, p), p).len() == 1)
+        .take(FACTOR_PRIME_CANDIDATES)
+    {
+        let f = monic_polynomial_mod(reduce_polynomial_mod(&polynomial, p), p);
+        let factors: Vec<_> = distinct_degree_factorization_mod(&f, p)
+            .into_iter()
+            .flat_map(|(g, d)| equal_degree_factorization_mod(g, d, p, &mut state))
+            .collect();
+        if factors.len() == 1
+        {
+            return vec![polynomial]
+        }
+        if factors.len() < best.as_ref().map_or(usize::MAX, |(_, best)| best.len())
+        {
+            best = Some((p, factors));
+        }
+    }
+    let (p, factors) = best.unwrap_or_else(|| unreachable!());
+
+    // Lift to a power of the prime exceeding twice the largest coefficient of lead times any factor, which can be far beyond 2^64
+    let norm = polynomial.iter()
+        .map(|&c| (c as f64)*(c as f64))
+        .sum::<f64>()
+        .sqrt();
+    let bound_bits = 1.0 + (lead as f64).log2() + n as f64 + norm.log2();
+    let mut k = 1;
+    let mut m = BigInt::from(p);
+    while ((m.bits() - 1) as f64) <= bound_bits
+    {
+        m *= p;
+        k += 1;
+    }
+    let mut f: Vec<BigInt> = polynomial.into_iter()
+        .map(BigInt::from)
+        .collect();
+    let mut lifted = hensel_lift_factors(&reduce_big_polynomial_mod(&f, &m), &factors, p, k);
+
+    // Recombine subsets of the lifted factors, smallest first, keeping the ones giving true factors
+    let mut irreducible = vec![];
+    let mut s = 1;
+    'subsets: while 2*s <= lifted.len()
+    {
+        let mut subset: Vec<usize> = (0..s).collect();
+        loop
+        {
+            let lead = &f[f.len() - 1] % &m;
+            let g = subset.iter()
+                .fold(vec![lead], |g, &i| mul_big_polynomial_mod(&g, &lifted[i], &m));
+            let g = primitive_big_polynomial(lift_big_polynomial_mod(&g, &m));
+            if let Some(q) = exact_div_big_polynomial(&f, &g)
+            {
+                irreducible.push(g);
+                f = q;
+                for &i in subset.iter().rev()
+                {
+                    lifted.remove(i);
+                }
+                continue 'subsets
+            }
+            if !next_combination(&mut subset, lifted.len())
+            {
+                break
+            }
+        }
+        s += 1;
+    }
+    if f.len() > 1
+    {
+        irreducible.push(f);
+    }
+    irreducible.into_iter()
+        .map(|g| g.into_iter()
+            .map(|c| i128::try_from(c).expect("a factor has a coefficient that does not fit in an i128"))
+            .collect()
+        ).collect()
+}
+
+fn factor_polynomial(polynomial: &[i128]) -> (i128, Vec<(Vec<i128>, usize)>)
+{
+    let p = trim_polynomial(polynomial.to_vec());
+    let Some(&lead) = p.last()
+    else
+    {
+        return (0, vec![])
+    };
+    let content = content_polynomial(&p);
+    let content = if lead < 0 {-content} else {content};
+    let p = primitive_polynomial(p);
+
+    // Big integers are only a fallback, for when Mignotte's bound exceeds the primes of the modular gcd
+    let big = |p: &[i128]| p.iter()
+        .map(|&c| BigInt::from(c))
+        .collect::<Vec<_>>();
+    let mut factors: Vec<_> = yun_square_free_decomposition(
+        &p,
+        |a, b| modular_gcd_polynomial(a, b)
+            .unwrap_or_else(|| gcd_big_polynomial(&big(a), &big(b))
+                .into_iter()
+                .map(|c| i128::try_from(c).expect("a factor has a coefficient that does not fit in an i128"))
+                .collect()
+            ),
+        |d, _| d
+    ).into_iter()
+        .flat_map(|(f, e)| factor_square_free_polynomial(f)
+            .into_iter()
+            .map(move |f| (f, e))
+        ).collect();
+    factors.sort_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev())));
+    (content, factors)
+}
+
+impl<C> FactorPolynomial for &[C]
+where
+    C: Into<i128> + Copy
+{
+    fn factor_polynomial(self) -> (i128, Vec<(Vec<i128>, usize)>)
+    {
+        factor_polynomial(&self.iter()
+            .map(|&c| c.into())
+            .collect::<Vec<_>>()
+        )
+    }
+}
+
+impl<C, A> FactorPolynomial for Vec<C, A>
+where
+    A: Allocator,
+    C: Into<i128> + Copy
+{
+    fn factor_polynomial(self) -> (i128, Vec<(Vec<i128>, usize)>)
+    {
+        self.as_slice().factor_polynomial()
+    }
+}
+
+impl<C, const N: usize> FactorPolynomial for [C; N]
+where
+    C: Into<i128> + Copy
+{
+    fn factor_polynomial(self) -> (i128, Vec<(Vec<i128>, usize)>)
+    {
+        self.as_slice().factor_polynomial()
+    }
+}
@@ -0,0 +1,249 @@
+use core::{alloc::Allocator, ops::{Add, Mul, Sub}};
+
+use num_complex::Complex;
+use num_traits::{Float, FloatConst, One};
+
+use super::*;
+
+/// The shortest length of both polynomials for which [mul_polynomial](MulPolynomial::mul_polynomial) uses a fast transform instead of Karatsuba's algorithm,
+/// for complex numbers and integers modulo a prime
+/// 
+/// With the benchmarks in `benches/mul_polynomial.rs`, the transform breaks even with Karatsuba's algorithm at about 64 coefficients of [ModInt<NTT_PRIME>](ModInt)
+/// or `Complex<f64>`, and is about five times as fast at 1024 coefficients.
+pub const FFT_MULTIPLICATION_THRESHOLD: usize = 64;
+
+/// The shortest length of both polynomials for which [mul_polynomial](MulPolynomial::mul_polynomial) uses a fast transform instead of Karatsuba's algorithm,
+/// for real floating point numbers
+/// 
+/// Real coefficients are transformed as complex numbers, so that half of the work is wasted. With the benchmarks in `benches/mul_polynomial.rs`,
+/// the transform breaks even with Karatsuba's algorithm at about 224 coefficients of `f32` or `f64`, and is about three times as fast at 1024 coefficients.
+pub const REAL_FFT_MULTIPLICATION_THRESHOLD: usize = 224;
+
+/// Coefficients which can be convolved with a fast transform
+/// 
+/// Floating point numbers and complex numbers use the fast Fourier transform, and are subject to rounding errors relative to the largest coefficients.
+/// Integers modulo a prime, [ModInt](ModInt), use the number-theoretic transform, which is exact.
+/// 
+/// Slices and vectors of these coefficients are multiplied with the transform by [mul_polynomial](MulPolynomial::mul_polynomial) when both polynomials
+/// have at least [THRESHOLD](FftCoefficient::THRESHOLD) coefficients.
+pub trait FftCoefficient: Sized
+{
+    /// The shortest length of both polynomials for which the transform is faster than Karatsuba's algorithm
+    const THRESHOLD: usize = FFT_MULTIPLICATION_THRESHOLD;
+
+    /// Convolves two nonempty sequences of coefficients using a fast transform
+    fn convolve_fft(lhs: &[Self], rhs: &[Self]) -> Vec<Self>;
+}
+
+impl<C> MulCoefficients<C> for C
+where
+    C: FftCoefficient + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Default + Copy
+{
+    fn mul_coefficients(lhs: &[C], rhs: &[C]) -> Vec<C>
+    {
+        if lhs.len().min(rhs.len()) < C::THRESHOLD
+        {
+            return karatsuba_mul_polynomial(lhs, rhs)
+        }
+        C::convolve_fft(lhs, rhs)
+    }
+}
+
+pub trait FastMulPolynomial<Rhs>
+{
+    type Output;
+
+    /// Multiplies two polynomials using a fast transform, regardless of their lengths
+    /// 
+    /// [mul_polynomial](MulPolynomial::mul_polynomial) already uses the transform for long polynomials, so this is only needed to force it for shorter ones.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// type M = ModInt<NTT_PRIME>;
+    /// 
+    /// // (1 + 2x + 3x^2)^2 = 1 + 4x + 10x^2 + 12x^3 + 9x^4
+    /// let p: Vec<M> = [1, 2, 3].map(M::new).to_vec();
+    /// 
+    /// assert_eq!(
+    ///     p.clone().mul_polynomial_fft(p),
+    ///     [1, 4, 10, 12, 9].map(M::new).to_vec()
+    /// );
+    /// ```
+    fn mul_polynomial_fft(self, rhs: Rhs) -> Self::Output;
+}
+
+/// The iterative radix-2 transform in place, where the length is a power of two, and `roots[j]` is the `j`th power of a primitive root of unity of that order
+fn transform<T>(a: &mut [T], roots: &[T])
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy
+{
+    let n = a.len();
+    let bits = n.trailing_zeros();
+    if bits == 0
+    {
+        return
+    }
+    for i in 0..n
+    {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j
+        {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n
+    {
+        let stride = n/len;
+        for chunk in a.chunks_exact_mut(len)
+        {
+            let (lo, hi) = chunk.split_at_mut(len/2);
+            for (j, (u, v)) in lo.iter_mut()
+                .zip(hi.iter_mut())
+                .enumerate()
+            {
+                let t = *v*roots[j*stride];
+                (*u, *v) = (*u + t, *u - t);
+            }
+        }
+        len *= 2;
+    }
+}
+
+fn convolve_complex<F>(lhs: &[Complex<F>], rhs: &[Complex<F>]) -> Vec<Complex<F>>
+where
+    F: Float + FloatConst
+{
+    let len = lhs.len() + rhs.len() - 1;
+    let n = len.next_power_of_two();
+    let roots = |sign: F| (0..n/2)
+        .map(|j| Complex::from_polar(F::one(), sign*F::TAU()*F::from(j).unwrap()/F::from(n).unwrap()))
+        .collect::<Vec<_>>();
+
+    let mut a = lhs.to_vec();
+    let mut b = rhs.to_vec();
+    a.resize(n, Complex::from(F::zero()));
+    b.resize(n, Complex::from(F::zero()));
+
+    let forward = roots(-F::one());
+    transform(&mut a, &forward);
+    transform(&mut b, &forward);
+    for (a, b) in a.iter_mut()
+        .zip(b)
+    {
+        *a = *a*b;
+    }
+    transform(&mut a, &roots(F::one()));
+
+    let scale = F::from(n).unwrap().recip();
+    a.truncate(len);
+    a.into_iter()
+        .map(|c| c*scale)
+        .collect()
+}
+
+macro_rules! impl_fft_coefficient {
+    ($($float:ty),*) => {
+        $(
+            impl FftCoefficient for $float
+            {
+                const THRESHOLD: usize = REAL_FFT_MULTIPLICATION_THRESHOLD;
+
+                fn convolve_fft(lhs: &[Self], rhs: &[Self]) -> Vec<Self>
+                {
+                    let complex = |p: &[Self]| p.iter()
+                        .map(|&c| Complex::from(c))
+                        .collect::<Vec<_>>();
+                    convolve_complex(&complex(lhs), &complex(rhs))
+                        .into_iter()
+                        .map(|c| c.re)
+                        .collect()
+                }
+            }
+
+            impl FftCoefficient for Complex<$float>
+            {
+                fn convolve_fft(lhs: &[Self], rhs: &[Self]) -> Vec<Self>
+                {
+                    convolve_complex(lhs, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_fft_coefficient!(f32, f64);
+
+impl<const P: u64> FftCoefficient for ModInt<P>
+{
+    /// Convolves using the number-theoretic transform, falling back to direct convolution if `P - 1` is not divisible by the transform length
+    fn convolve_fft(lhs: &[Self], rhs: &[Self]) -> Vec<Self>
+    {
+        let len = lhs.len() + rhs.len() - 1;
+        let n = len.next_power_of_two();
+        if !(P - 1).is_multiple_of(n as u64)
+        {
+            return karatsuba_mul_polynomial(lhs, rhs)
+        }
+
+        // The primitive root is only found once P - 1 is known to have a root of unity of order n
+        let w = Self::primitive_root().pow((P - 1)/n as u64);
+        let roots = |w: Self| (0..n/2)
+            .scan(Self::one(), |wj, _| {
+                let y = *wj;
+                *wj *= w;
+                Some(y)
+            }).collect::<Vec<_>>();
+
+        let mut a = lhs.to_vec();
+        let mut b = rhs.to_vec();
+        a.resize(n, Self::default());
+        b.resize(n, Self::default());
+
+        let forward = roots(w);
+        transform(&mut a, &forward);
+        transform(&mut b, &forward);
+        for (a, b) in a.iter_mut()
+            .zip(b)
+        {
+            *a *= b;
+        }
+        transform(&mut a, &roots(w.inv()));
+
+        let scale = Self::new(n as u64).inv();
+        a.truncate(len);
+        a.into_iter()
+            .map(|c| c*scale)
+            .collect()
+    }
+}
+
+macro_rules! impl_fast_mul_polynomial {
+    ($(<{$($generics:tt)+}>)? [$rhs:ty] for $type:ty $(where $($where:tt)+)?) => {
+        impl<C, $($($generics)+)?> FastMulPolynomial<$rhs> for $type
+        where
+            C: FftCoefficient + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Default + Copy
+            $(,$($where)+)?
+        {
+            type Output = Vec<C>;
+
+            fn mul_polynomial_fft(self, rhs: $rhs) -> Self::Output
+            {
+                if self.is_empty() || rhs.is_empty()
+                {
+                    return vec![]
+                }
+                C::convolve_fft(&self[..], &rhs[..])
+            }
+        }
+    };
+}
+
+impl_fast_mul_polynomial!([&[C]] for &[C]);
+impl_fast_mul_polynomial!(<{A}> [Vec<C, A>] for &[C] where A: Allocator);
+impl_fast_mul_polynomial!(<{A}> [&[C]] for Vec<C, A> where A: Allocator);
+impl_fast_mul_polynomial!(<{A1, A2}> [Vec<C, A2>] for Vec<C, A1> where A1: Allocator, A2: Allocator);
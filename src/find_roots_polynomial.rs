@@ -0,0 +1,240 @@
+use core::{alloc::Allocator, fmt::Display};
+
+use num_complex::Complex;
+use num_traits::{Float, FloatConst, One, Zero};
+
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FindRootsError
+{
+    /// Every coefficient is zero, so every point is a root
+    ZeroPolynomial,
+    /// The roots did not converge within the maximum number of iterations
+    NoConvergence
+}
+
+impl Display for FindRootsError
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        match self
+        {
+            Self::ZeroPolynomial => write!(f, "root finding on the zero polynomial"),
+            Self::NoConvergence => write!(f, "root finding did not converge")
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FindRootsError
+{
+
+}
+
+/// The simultaneous iteration used by [find_roots](FindRootsPolynomial::find_roots)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FindRootsMethod
+{
+    /// The Aberth–Ehrlich method, which converges cubically for simple roots
+    #[default]
+    Aberth,
+    /// The Durand–Kerner (Weierstrass) method, which converges quadratically for simple roots, but is cheaper per iteration
+    DurandKerner
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FindRootsOptions<F>
+{
+    /// A root is converged once its correction is smaller than the tolerance relative to its modulus, or to one for small roots
+    pub tolerance: F,
+    /// The maximum number of sweeps over all the roots
+    pub max_iterations: usize,
+    pub method: FindRootsMethod
+}
+
+impl<F> Default for FindRootsOptions<F>
+where
+    F: Float
+{
+    fn default() -> Self
+    {
+        Self {
+            tolerance: F::epsilon()*F::from(16).unwrap(),
+            max_iterations: 500,
+            method: FindRootsMethod::default()
+        }
+    }
+}
+
+pub trait FindRootsPolynomial
+{
+    type Coefficient;
+    type Roots;
+
+    /// Finds all the complex roots of a polynomial of any degree, by refining approximations of every root simultaneously
+    /// 
+    /// The initial approximations are spread on a circle enclosing every root, and are updated using the [method](FindRootsMethod) given in the options
+    /// until every correction is within the tolerance, or the value of the polynomial at the root is as small as rounding errors allow.
+    /// Multiple roots converge more slowly, and only to about `1/m` of the digits for a root of multiplicity `m`.
+    /// 
+    /// For arrays, a polynomial with `N` coefficients has `N - 1` roots, where the roots missing due to zero leading coefficients are given as positive infinity.
+    /// For slices and vectors, trailing zeros are ignored.
+    /// 
+    /// # Errors
+    /// 
+    /// Yields [FindRootsError::ZeroPolynomial](FindRootsError::ZeroPolynomial) if every coefficient is zero,
+    /// and [FindRootsError::NoConvergence](FindRootsError::NoConvergence) if the roots have not converged within the maximum number of iterations.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// // x^10 - 1
+    /// let mut p = [0.0f64; 11];
+    /// p[0] = -1.0;
+    /// p[10] = 1.0;
+    /// 
+    /// let roots = p.find_roots(FindRootsOptions::default()).unwrap();
+    /// for z in roots
+    /// {
+    ///     assert!((z.norm() - 1.0).abs() < 1e-12);
+    ///     assert!(p.evaluate_as_polynomial(z).norm() < 1e-12);
+    /// }
+    /// ```
+    fn find_roots(self, options: FindRootsOptions<Self::Coefficient>) -> Result<Self::Roots, FindRootsError>;
+}
+
+/// Evaluates a polynomial and its derivative at a complex point, along with the sum of the absolute values of every term, which bounds the rounding error
+fn evaluate_with_derivative<F>(polynomial: &[F], z: Complex<F>) -> (Complex<F>, Complex<F>, F)
+where
+    F: Float
+{
+    let r = z.norm();
+    polynomial.iter()
+        .rev()
+        .fold((Complex::zero(), Complex::zero(), F::zero()), |(y, dy, scale), &c| (y*z + c, dy*z + y, scale*r + c.abs()))
+}
+
+/// Finds the roots of a polynomial with a nonzero leading coefficient, where `z` has room for every root
+fn find_roots<F>(polynomial: &[F], z: &mut [Complex<F>], options: &FindRootsOptions<F>) -> Result<(), FindRootsError>
+where
+    F: Float + FloatConst
+{
+    let degree = z.len();
+    if degree == 0
+    {
+        return Ok(())
+    }
+
+    let lead = polynomial[degree];
+    // Fujiwara's bound grows like the roots themselves, unlike Cauchy's bound, so the polynomial does not overflow on the initial circle
+    let radius = root_bound_fujiwara(polynomial);
+    let radius = if radius.is_zero() {F::one()} else {radius};
+    let angle = F::TAU()/F::from(degree).unwrap();
+    let offset = F::from(0.4).unwrap();
+    for (k, z) in z.iter_mut()
+        .enumerate()
+    {
+        *z = Complex::from_polar(radius, angle*F::from(k).unwrap() + offset);
+    }
+
+    let rounding = F::epsilon()*F::from(4*degree).unwrap();
+    for _ in 0..options.max_iterations
+    {
+        let mut converged = true;
+        for i in 0..degree
+        {
+            let zi = z[i];
+            let (y, dy, scale) = evaluate_with_derivative(polynomial, zi);
+            if y.norm() <= rounding*scale
+            {
+                continue
+            }
+
+            let others = z.iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, &zj)| zi - zj);
+            let w = match options.method
+            {
+                FindRootsMethod::Aberth => (dy/y - others.map(|d| d.inv()).sum::<Complex<F>>()).inv(),
+                FindRootsMethod::DurandKerner => y/(others.product::<Complex<F>>()*lead)
+            };
+            if !w.re.is_finite() || !w.im.is_finite()
+            {
+                converged = false;
+                continue
+            }
+
+            z[i] = zi - w;
+            if w.norm() > options.tolerance*z[i].norm().max(F::one())
+            {
+                converged = false;
+            }
+        }
+        if converged
+        {
+            return Ok(())
+        }
+    }
+    Err(FindRootsError::NoConvergence)
+}
+
+impl<F, const N: usize> FindRootsPolynomial for [F; N]
+where
+    F: Float + FloatConst,
+    [(); N - 1]:
+{
+    type Coefficient = F;
+    type Roots = [Complex<F>; N - 1];
+
+    fn find_roots(self, options: FindRootsOptions<F>) -> Result<Self::Roots, FindRootsError>
+    {
+        let len = self.iter()
+            .rposition(|c| !c.is_zero())
+            .ok_or(FindRootsError::ZeroPolynomial)? + 1;
+
+        let mut z = [Complex::new(F::infinity(), F::zero()); N - 1];
+        find_roots(&self[..len], &mut z[..len - 1], &options)?;
+        Ok(z)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F> FindRootsPolynomial for &[F]
+where
+    F: Float + FloatConst
+{
+    type Coefficient = F;
+    type Roots = Vec<Complex<F>>;
+
+    fn find_roots(self, options: FindRootsOptions<F>) -> Result<Self::Roots, FindRootsError>
+    {
+        let len = self.iter()
+            .rposition(|c| !c.is_zero())
+            .ok_or(FindRootsError::ZeroPolynomial)? + 1;
+
+        let mut z = vec![Complex::one(); len - 1];
+        find_roots(&self[..len], &mut z, &options)?;
+        Ok(z)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, A> FindRootsPolynomial for Vec<F, A>
+where
+    A: Allocator,
+    F: Float + FloatConst
+{
+    type Coefficient = F;
+    type Roots = Vec<Complex<F>>;
+
+    fn find_roots(self, options: FindRootsOptions<F>) -> Result<Self::Roots, FindRootsError>
+    {
+        self.as_slice().find_roots(options)
+    }
+}
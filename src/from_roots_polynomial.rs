@@ -0,0 +1,127 @@
+use core::{alloc::Allocator, ops::{AddAssign, Mul, Neg}};
+
+use array__ops::ArrayOps;
+use num_traits::One;
+
+use super::*;
+
+#[allow(clippy::wrong_self_convention)]
+pub trait FromRootsPolynomial
+{
+    type Coefficient;
+    type Output;
+
+    /// Yields the coefficients of the monic polynomial with the given roots, `(x - r0)(x - r1)...`
+    /// 
+    /// The factors `[-r, 1]` are multiplied using [ProductPolynomial](ProductPolynomial), so `N` roots give `N + 1` coefficients.
+    /// Repeated roots give roots of higher multiplicity, and no roots give the constant one.
+    /// 
+    /// Filters are often written with the factors `1 - r/z` instead, and their coefficients, in ascending powers of `1/z`, are these coefficients reversed.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 1)(x - 2)(x + 3) = x^3 - 7x + 6
+    /// let p = vec![1, 2, -3].from_roots();
+    /// assert_eq!(p, vec![6, -7, 0, 1]);
+    /// 
+    /// for r in [1, 2, -3]
+    /// {
+    ///     assert_eq!(p.as_slice().evaluate_as_polynomial(r), 0);
+    /// }
+    /// ```
+    fn from_roots(self) -> Self::Output;
+
+    /// Yields the coefficients of the polynomial with the given roots and leading coefficient, `a(x - r0)(x - r1)...`
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // 2(x - 1)(x + 1) = 2x^2 - 2
+    /// assert_eq!(
+    ///     vec![1.0, -1.0].from_roots_with_leading_coefficient(2.0),
+    ///     vec![-2.0, 0.0, 2.0]
+    /// );
+    /// ```
+    fn from_roots_with_leading_coefficient(self, leading_coefficient: Self::Coefficient) -> Self::Output;
+}
+
+impl<C, const N: usize> FromRootsPolynomial for [C; N]
+where
+    C: One + Neg<Output = C> + Mul<Output = C> + AddAssign + Default + Copy,
+    [(); polynomial_product_length(2, N)]:,
+    [(); N + 1]:
+{
+    type Coefficient = C;
+    type Output = [C; N + 1];
+
+    fn from_roots(self) -> Self::Output
+    {
+        self.from_roots_with_leading_coefficient(C::one())
+    }
+
+    fn from_roots_with_leading_coefficient(self, leading_coefficient: C) -> Self::Output
+    {
+        let p = self.map(|r| [-r, C::one()])
+            .product_polynomial();
+        ArrayOps::fill(|i| p.get(i)
+            .copied()
+            .unwrap_or_else(C::one)*leading_coefficient
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> FromRootsPolynomial for &[C]
+where
+    C: One + Neg<Output = C> + Mul<Output = C> + AddAssign + Default + Copy,
+    Vec<C>: MulPolynomial<Vec<C>, Output = Vec<C>>
+{
+    type Coefficient = C;
+    type Output = Vec<C>;
+
+    fn from_roots(self) -> Self::Output
+    {
+        self.from_roots_with_leading_coefficient(C::one())
+    }
+
+    fn from_roots_with_leading_coefficient(self, leading_coefficient: C) -> Self::Output
+    {
+        if self.is_empty()
+        {
+            return vec![leading_coefficient]
+        }
+        self.iter()
+            .map(|&r| [-r, C::one()])
+            .collect::<Vec<_>>()
+            .product_polynomial()
+            .into_iter()
+            .map(|c| c*leading_coefficient)
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A> FromRootsPolynomial for Vec<C, A>
+where
+    A: Allocator,
+    C: One + Neg<Output = C> + Mul<Output = C> + AddAssign + Default + Copy,
+    Vec<C>: MulPolynomial<Vec<C>, Output = Vec<C>>
+{
+    type Coefficient = C;
+    type Output = Vec<C>;
+
+    fn from_roots(self) -> Self::Output
+    {
+        self.as_slice().from_roots()
+    }
+
+    fn from_roots_with_leading_coefficient(self, leading_coefficient: C) -> Self::Output
+    {
+        self.as_slice().from_roots_with_leading_coefficient(leading_coefficient)
+    }
+}
@@ -0,0 +1,336 @@
+use core::{alloc::Allocator, ops::{Div, Mul, Neg, Rem, Sub}};
+
+use num_traits::{One, Zero};
+
+use super::*;
+
+pub trait GcdPolynomial<Rhs>
+{
+    type Output;
+    type Tolerance;
+
+    /// Finds the greatest common divisor of two polynomials using the euclidean algorithm
+    /// 
+    /// The coefficients are expected to form a field (floating point numbers, rationals). The result is monic, and empty if both polynomials are zero.
+    /// For integer coefficients, use [subresultant_gcd_polynomial](SubresultantGcdPolynomial::subresultant_gcd_polynomial) instead.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 1)(x - 2) and (x - 1)(x + 3) share the factor x - 1
+    /// assert_eq!(
+    ///     [2.0, -3.0, 1.0].gcd_polynomial([-3.0, 2.0, 1.0]),
+    ///     vec![-1.0, 1.0]
+    /// );
+    /// assert_eq!(
+    ///     [2.0, -3.0, 1.0].gcd_polynomial([3.0, 1.0]),
+    ///     vec![1.0]
+    /// );
+    /// ```
+    fn gcd_polynomial(self, rhs: Rhs) -> Self::Output;
+
+    /// Finds the greatest common divisor of two polynomials using the euclidean algorithm, treating small remainder coefficients as zero
+    /// 
+    /// Rounding errors mean that floating point remainders are rarely exactly zero. Here, leading coefficients of each remainder are dropped
+    /// while their magnitude is within `tolerance` times the largest coefficient of the monic dividend.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 0.1)(x - 0.7) and (x - 0.1)(x + 0.3) share the factor x - 0.1
+    /// let a: [f64; 3] = [-0.1, 1.0].mul_polynomial([-0.7, 1.0]);
+    /// let b = [-0.1, 1.0].mul_polynomial([0.3, 1.0]);
+    /// 
+    /// let d = a.gcd_polynomial_with_tolerance(b, 1e-9);
+    /// assert_eq!(d.len(), 2);
+    /// assert!((d[0] + 0.1).abs() < 1e-9);
+    /// ```
+    fn gcd_polynomial_with_tolerance(self, rhs: Rhs, tolerance: Self::Tolerance) -> Self::Output;
+}
+
+pub trait SubresultantGcdPolynomial<Rhs>
+{
+    type Output;
+
+    /// Finds the greatest common divisor of two polynomials using the subresultant polynomial remainder sequence
+    /// 
+    /// Every division along the way is exact, so this works for integer coefficients without leaving the ring, and keeps intermediate coefficients small.
+    /// The result includes the greatest common divisor of the contents, and has a positive leading coefficient.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // 2(x - 1)(x - 2) and 2(x - 1)(x + 3) share the factor 2(x - 1)
+    /// assert_eq!(
+    ///     [4i128, -6, 2].subresultant_gcd_polynomial([-6, 4, 2]),
+    ///     vec![-2, 2]
+    /// );
+    /// ```
+    fn subresultant_gcd_polynomial(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Removes trailing zero coefficients, so that the last coefficient is the leading one
+pub(crate) fn trim_polynomial<C>(mut polynomial: Vec<C>) -> Vec<C>
+where
+    C: Zero
+{
+    while polynomial.last().is_some_and(Zero::is_zero)
+    {
+        polynomial.pop();
+    }
+    polynomial
+}
+
+fn monic_polynomial<C>(polynomial: Vec<C>) -> Vec<C>
+where
+    C: Div<Output = C> + Copy
+{
+    match polynomial.last()
+    {
+        Some(&lead) => polynomial.into_iter()
+            .map(|c| c/lead)
+            .collect(),
+        None => polynomial
+    }
+}
+
+pub(crate) fn abs_coefficient<C>(c: C) -> C
+where
+    C: Zero + Neg<Output = C> + PartialOrd
+{
+    if c < C::zero() {-c} else {c}
+}
+
+pub(crate) fn gcd_coefficient<C>(mut a: C, mut b: C) -> C
+where
+    C: Zero + Rem<Output = C> + Neg<Output = C> + PartialOrd + Copy
+{
+    while !b.is_zero()
+    {
+        (a, b) = (b, a % b);
+    }
+    abs_coefficient(a)
+}
+
+/// The greatest common divisor of all coefficients, which is zero for the zero polynomial
+pub(crate) fn content_polynomial<C>(polynomial: &[C]) -> C
+where
+    C: Zero + Rem<Output = C> + Neg<Output = C> + PartialOrd + Copy
+{
+    polynomial.iter()
+        .fold(C::zero(), |a, &b| gcd_coefficient(a, b))
+}
+
+/// Divides out the content and makes the leading coefficient positive
+pub(crate) fn primitive_polynomial<C>(polynomial: Vec<C>) -> Vec<C>
+where
+    C: Zero + Rem<Output = C> + Div<Output = C> + Neg<Output = C> + PartialOrd + Copy
+{
+    let mut content = content_polynomial(&polynomial);
+    if content.is_zero()
+    {
+        return polynomial
+    }
+    if polynomial.last().is_some_and(|&lead| lead < C::zero())
+    {
+        content = -content
+    }
+    polynomial.into_iter()
+        .map(|c| c/content)
+        .collect()
+}
+
+/// Computes `lead(rhs)^(deg(lhs) - deg(rhs) + 1)*lhs mod rhs` without any divisions
+/// 
+/// Both polynomials must be trimmed, and `rhs` must not be zero.
+pub(crate) fn pseudo_rem_polynomial<C>(lhs: &[C], rhs: &[C]) -> Vec<C>
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Copy
+{
+    let rhs_len = rhs.len();
+    let lead = rhs[rhs_len - 1];
+
+    let mut r = lhs.to_vec();
+    let mut e = (lhs.len() + 1).saturating_sub(rhs_len);
+    while r.len() >= rhs_len
+    {
+        let t = r[r.len() - 1];
+        let shift = r.len() - rhs_len;
+        for (k, c) in r.iter_mut()
+            .enumerate()
+        {
+            *c = *c*lead - if k >= shift {t*rhs[k - shift]} else {C::zero()};
+        }
+        r.pop();
+        r = trim_polynomial(r);
+        e -= 1;
+    }
+    for _ in 0..e
+    {
+        for c in r.iter_mut()
+        {
+            *c = *c*lead;
+        }
+    }
+    r
+}
+
+fn pow_coefficient<C>(c: C, n: usize) -> C
+where
+    C: One + Copy
+{
+    (0..n).fold(C::one(), |y, _| y*c)
+}
+
+/// The euclidean algorithm, where `negligible(c, a)` decides whether a leading remainder coefficient `c` is zero when dividing `a`
+fn euclid_gcd_polynomial_by<C>(lhs: &[C], rhs: &[C], negligible: impl Fn(&C, &[C]) -> bool) -> Vec<C>
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    let mut a = monic_polynomial(trim_polynomial(lhs.to_vec()));
+    let mut b = monic_polynomial(trim_polynomial(rhs.to_vec()));
+    while !b.is_empty()
+    {
+        let (_, mut r) = a.as_slice().div_rem_polynomial(b.as_slice())
+            .unwrap_or_else(|_| unreachable!());
+        while r.last().is_some_and(|c| negligible(c, &a))
+        {
+            r.pop();
+        }
+        a = b;
+        b = monic_polynomial(r);
+    }
+    a
+}
+
+pub(crate) fn euclid_gcd_polynomial<C>(lhs: &[C], rhs: &[C]) -> Vec<C>
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    euclid_gcd_polynomial_by(lhs, rhs, |c, _| c.is_zero())
+}
+
+pub(crate) fn euclid_gcd_polynomial_with_tolerance<C>(lhs: &[C], rhs: &[C], tolerance: C) -> Vec<C>
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + PartialOrd + Copy
+{
+    euclid_gcd_polynomial_by(lhs, rhs, |&c, a| {
+        let scale = a.iter()
+            .map(|&c| abs_coefficient(c))
+            .fold(C::zero(), |a, b| if b > a {b} else {a});
+        abs_coefficient(c) <= tolerance*scale
+    })
+}
+
+pub(crate) fn subresultant_gcd_polynomial<C>(lhs: &[C], rhs: &[C]) -> Vec<C>
+where
+    C: Zero + One + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Rem<Output = C> + Neg<Output = C> + PartialOrd + Copy
+{
+    let mut a = trim_polynomial(lhs.to_vec());
+    let mut b = trim_polynomial(rhs.to_vec());
+    if a.len() < b.len()
+    {
+        core::mem::swap(&mut a, &mut b);
+    }
+    if b.is_empty()
+    {
+        let content = content_polynomial(&a);
+        return primitive_polynomial(a)
+            .into_iter()
+            .map(|c| c*content)
+            .collect()
+    }
+
+    let content = gcd_coefficient(content_polynomial(&a), content_polynomial(&b));
+    a = primitive_polynomial(a);
+    b = primitive_polynomial(b);
+
+    let mut g = C::one();
+    let mut h = C::one();
+    loop
+    {
+        let delta = a.len() - b.len();
+        let r = pseudo_rem_polynomial(&a, &b);
+        if r.is_empty()
+        {
+            break
+        }
+        if r.len() == 1
+        {
+            b = vec![C::one()];
+            break
+        }
+
+        let scale = g*pow_coefficient(h, delta);
+        a = b;
+        b = r.into_iter()
+            .map(|c| c/scale)
+            .collect();
+        g = a[a.len() - 1];
+        if delta > 0
+        {
+            h = pow_coefficient(g, delta)/pow_coefficient(h, delta - 1);
+        }
+    }
+
+    primitive_polynomial(b)
+        .into_iter()
+        .map(|c| c*content)
+        .collect()
+}
+
+macro_rules! impl_gcd_polynomial {
+    ($(<{$($generics:tt)+}>)? [$rhs:ty] for $type:ty $(where $($where:tt)+)?) => {
+        impl<C, $($($generics)+)?> GcdPolynomial<$rhs> for $type
+        where
+            C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + PartialOrd + Copy
+            $(,$($where)+)?
+        {
+            type Output = Vec<C>;
+            type Tolerance = C;
+
+            fn gcd_polynomial(self, rhs: $rhs) -> Self::Output
+            {
+                euclid_gcd_polynomial(&self[..], &rhs[..])
+            }
+
+            fn gcd_polynomial_with_tolerance(self, rhs: $rhs, tolerance: C) -> Self::Output
+            {
+                euclid_gcd_polynomial_with_tolerance(&self[..], &rhs[..], tolerance)
+            }
+        }
+
+        impl<C, $($($generics)+)?> SubresultantGcdPolynomial<$rhs> for $type
+        where
+            C: Zero + One + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Rem<Output = C> + Neg<Output = C> + PartialOrd + Copy
+            $(,$($where)+)?
+        {
+            type Output = Vec<C>;
+
+            fn subresultant_gcd_polynomial(self, rhs: $rhs) -> Self::Output
+            {
+                subresultant_gcd_polynomial(&self[..], &rhs[..])
+            }
+        }
+    };
+}
+
+impl_gcd_polynomial!([&[C]] for &[C]);
+impl_gcd_polynomial!(<{A}> [Vec<C, A>] for &[C] where A: Allocator);
+impl_gcd_polynomial!(<{const N: usize}> [[C; N]] for &[C]);
+
+impl_gcd_polynomial!(<{A}> [&[C]] for Vec<C, A> where A: Allocator);
+impl_gcd_polynomial!(<{A1, A2}> [Vec<C, A2>] for Vec<C, A1> where A1: Allocator, A2: Allocator);
+impl_gcd_polynomial!(<{A, const N: usize}> [[C; N]] for Vec<C, A> where A: Allocator);
+
+impl_gcd_polynomial!(<{const N: usize}> [&[C]] for [C; N]);
+impl_gcd_polynomial!(<{A, const N: usize}> [Vec<C, A>] for [C; N] where A: Allocator);
+impl_gcd_polynomial!(<{const N1: usize, const N2: usize}> [[C; N2]] for [C; N1]);
@@ -0,0 +1,118 @@
+use core::{alloc::Allocator, ops::{Add, Div, Sub}};
+
+use array__ops::ArrayOps;
+use num_traits::{One, Zero};
+
+use super::*;
+
+#[const_trait]
+pub trait IntegratePolynomial: Sized
+{
+    type Coefficient;
+    type Output;
+
+    /// Integrates a polynomial, and yields a polynomial one coefficient longer, where the first coefficient is the integration constant
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// // ∫ (2 + 6x + 12x^2) dx = 1 + 2x + 3x^2 + 4x^3, given the integration constant 1
+    /// assert_eq!(
+    ///     [2.0, 6.0, 12.0].integrate_polynomial(1.0),
+    ///     [1.0, 2.0, 3.0, 4.0]
+    /// );
+    /// assert_eq!(
+    ///     vec![2.0, 6.0].integrate_polynomial(0.0),
+    ///     vec![0.0, 2.0, 3.0]
+    /// );
+    /// ```
+    fn integrate_polynomial(self, constant: Self::Coefficient) -> Self::Output;
+
+    /// Integrates a polynomial from `a` to `b`
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// // ∫ (1 + 2x + 3x^2) dx from 0 to 2 = 2 + 2^2 + 2^3
+    /// assert_eq!(
+    ///     [1.0, 2.0, 3.0].definite_integral(0.0, 2.0),
+    ///     14.0
+    /// );
+    /// ```
+    fn definite_integral<X>(self, a: X, b: X) -> <Self::Output as Polynomial<X>>::Y
+    where
+        Self::Coefficient: Zero,
+        Self::Output: Polynomial<X> + Clone,
+        <Self::Output as Polynomial<X>>::Y: Sub<Output = <Self::Output as Polynomial<X>>::Y>
+    {
+        let integral = self.integrate_polynomial(Zero::zero());
+        integral.clone().evaluate_as_polynomial(b) - integral.evaluate_as_polynomial(a)
+    }
+}
+
+impl<C, const N: usize> /*const*/ IntegratePolynomial for [C; N]
+where
+    C: /*~const*/ Zero + /*~const*/ One + /*~const*/ Add<Output = C> + /*~const*/ Div<Output = C> + Copy,
+    [(); N + 1]:
+{
+    type Coefficient = C;
+    type Output = [C; N + 1];
+
+    fn integrate_polynomial(self, constant: C) -> Self::Output
+    {
+        let mut n = C::zero();
+        ArrayOps::fill(/*const*/ |k| if k == 0
+            {
+                constant
+            }
+            else
+            {
+                n = n + C::one();
+                self[k - 1]/n
+            }
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> IntegratePolynomial for &[C]
+where
+    C: Zero + One + Add<Output = C> + Div<Output = C> + Copy
+{
+    type Coefficient = C;
+    type Output = Vec<C>;
+
+    fn integrate_polynomial(self, constant: C) -> Self::Output
+    {
+        [constant].into_iter()
+            .chain(self.iter()
+                .scan(C::zero(), |n, &c| {
+                    *n = *n + C::one();
+                    Some(c/(*n))
+                })
+            ).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A> IntegratePolynomial for Vec<C, A>
+where
+    A: Allocator,
+    C: Zero + One + Add<Output = C> + Div<Output = C> + Copy
+{
+    type Coefficient = C;
+    type Output = Vec<C>;
+
+    fn integrate_polynomial(self, constant: C) -> Self::Output
+    {
+        self.as_slice().integrate_polynomial(constant)
+    }
+}
@@ -0,0 +1,324 @@
+use core::{alloc::Allocator, fmt::Display, ops::{Add, Div, Mul, Sub}};
+
+use num_traits::Zero;
+
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterpolatePolynomialError
+{
+    /// Two of the nodes are equal, so no polynomial passes through all of the points
+    DuplicateNode,
+    /// There is not the same number of nodes and values
+    LengthMismatch
+}
+
+impl Display for InterpolatePolynomialError
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        match self
+        {
+            Self::DuplicateNode => write!(f, "interpolation through two points with the same node"),
+            Self::LengthMismatch => write!(f, "interpolation with a different number of nodes and values")
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InterpolatePolynomialError
+{
+
+}
+
+#[const_trait]
+pub trait InterpolatePolynomial<Y>
+{
+    type Output;
+
+    /// Yields the coefficients of the polynomial of lowest degree passing through the points `(x[i], y[i])`, where `x` is `self`
+    /// 
+    /// This is the inverse of [evaluate_as_polynomial](Polynomial::evaluate_as_polynomial), and `N` points give a polynomial with `N` coefficients.
+    /// The coefficients are found using Newton's divided differences, which are then expanded into the monomial basis.
+    /// See [NewtonPolynomial](NewtonPolynomial) for adding points one at a time.
+    /// 
+    /// The coefficients are expected to form a field (floating point numbers, rationals). For integers, the result is only exact if every division along the way is.
+    /// 
+    /// # Errors
+    /// 
+    /// Yields [InterpolatePolynomialError::DuplicateNode](InterpolatePolynomialError::DuplicateNode) if two of the nodes are equal,
+    /// and [InterpolatePolynomialError::LengthMismatch](InterpolatePolynomialError::LengthMismatch) if slices or vectors of different lengths are given.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// const P: [f64; 3] = [1.0, -2.0, 3.0];
+    /// 
+    /// let x = [-1.0, 0.0, 2.0];
+    /// let y = x.map(|x| P.evaluate_as_polynomial(x));
+    /// 
+    /// assert_eq!(x.interpolate_polynomial(y), Ok(P));
+    /// assert_eq!(
+    ///     [1.0, 1.0].interpolate_polynomial([2.0, 3.0]),
+    ///     Err(InterpolatePolynomialError::DuplicateNode)
+    /// );
+    /// ```
+    fn interpolate_polynomial(self, y: Y) -> Result<Self::Output, InterpolatePolynomialError>;
+}
+
+impl<C, const N: usize> const InterpolatePolynomial<[C; N]> for [C; N]
+where
+    C: /*~const*/ Zero + /*~const*/ Sub<Output = C> + /*~const*/ Mul<Output = C> + /*~const*/ Div<Output = C> + Copy
+{
+    type Output = [C; N];
+
+    fn interpolate_polynomial(self, y: [C; N]) -> Result<Self::Output, InterpolatePolynomialError>
+    {
+        // Divided differences, computed in place
+        let mut d = y;
+        let mut j = 1;
+        while j < N
+        {
+            let mut i = N - 1;
+            while i >= j
+            {
+                let dx = self[i] - self[i - j];
+                if dx.is_zero()
+                {
+                    return Err(InterpolatePolynomialError::DuplicateNode)
+                }
+                d[i] = (d[i] - d[i - 1])/dx;
+                i -= 1;
+            }
+            j += 1;
+        }
+
+        // Horner's method on the Newton form, multiplying by `x - x[k]` at each step
+        let mut p = [C::zero(); N];
+        let mut k = N;
+        while k > 0
+        {
+            k -= 1;
+
+            let mut i = N - 1;
+            while i > 0
+            {
+                p[i] = p[i - 1] - self[k]*p[i];
+                i -= 1;
+            }
+            p[0] = d[k] - self[k]*p[0];
+        }
+        Ok(p)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> InterpolatePolynomial<&[C]> for &[C]
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Output = Vec<C>;
+
+    fn interpolate_polynomial(self, y: &[C]) -> Result<Self::Output, InterpolatePolynomialError>
+    {
+        if self.len() != y.len()
+        {
+            return Err(InterpolatePolynomialError::LengthMismatch)
+        }
+
+        let mut newton = NewtonPolynomial::new();
+        for (&x, &y) in self.iter()
+            .zip(y.iter())
+        {
+            newton.push(x, y)?;
+        }
+        Ok(newton.to_polynomial())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A1, A2> InterpolatePolynomial<Vec<C, A2>> for Vec<C, A1>
+where
+    A1: Allocator,
+    A2: Allocator,
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Output = Vec<C>;
+
+    fn interpolate_polynomial(self, y: Vec<C, A2>) -> Result<Self::Output, InterpolatePolynomialError>
+    {
+        self.as_slice().interpolate_polynomial(y.as_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A1> InterpolatePolynomial<&[C]> for Vec<C, A1>
+where
+    A1: Allocator,
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Output = Vec<C>;
+
+    fn interpolate_polynomial(self, y: &[C]) -> Result<Self::Output, InterpolatePolynomialError>
+    {
+        self.as_slice().interpolate_polynomial(y)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A2> InterpolatePolynomial<Vec<C, A2>> for &[C]
+where
+    A2: Allocator,
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Output = Vec<C>;
+
+    fn interpolate_polynomial(self, y: Vec<C, A2>) -> Result<Self::Output, InterpolatePolynomialError>
+    {
+        self.interpolate_polynomial(y.as_slice())
+    }
+}
+
+/// A polynomial in Newton form, which can be extended one point at a time
+/// 
+/// The polynomial is `c[0] + c[1](x - x[0]) + c[2](x - x[0])(x - x[1]) + ...`, where `c` are the divided differences of the points.
+/// Adding a point takes `O(n)` operations, and does not change any of the previous coefficients.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use polynomial_ops::*;
+/// 
+/// let mut newton = NewtonPolynomial::new();
+/// newton.push(0.0, 1.0).unwrap();
+/// newton.push(1.0, 3.0).unwrap();
+/// assert_eq!(newton.to_polynomial(), vec![1.0, 2.0]);
+/// 
+/// newton.push(2.0, 7.0).unwrap();
+/// assert_eq!(newton.to_polynomial(), vec![1.0, 1.0, 1.0]);
+/// assert_eq!((&newton).evaluate_as_polynomial(3.0), 13.0);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewtonPolynomial<C>
+{
+    nodes: Vec<C>,
+    coefficients: Vec<C>,
+    diagonal: Vec<C>
+}
+
+#[cfg(feature = "std")]
+impl<C> NewtonPolynomial<C>
+{
+    /// Creates a Newton polynomial through no points, which is the zero polynomial
+    pub const fn new() -> Self
+    {
+        Self {
+            nodes: vec![],
+            coefficients: vec![],
+            diagonal: vec![]
+        }
+    }
+
+    /// The nodes of the points added so far, in order
+    pub fn nodes(&self) -> &[C]
+    {
+        &self.nodes
+    }
+
+    /// The coefficients of the polynomial in Newton form, which are the divided differences `f[x[0], ..., x[k]]`
+    pub fn coefficients(&self) -> &[C]
+    {
+        &self.coefficients
+    }
+
+    /// The number of points added so far
+    pub fn len(&self) -> usize
+    {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.nodes.is_empty()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> NewtonPolynomial<C>
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    /// Adds the point `(x, y)`, raising the degree of the polynomial by one
+    /// 
+    /// # Errors
+    /// 
+    /// Yields [InterpolatePolynomialError::DuplicateNode](InterpolatePolynomialError::DuplicateNode) if `x` has already been added, leaving the polynomial unchanged.
+    pub fn push(&mut self, x: C, y: C) -> Result<(), InterpolatePolynomialError>
+    {
+        // The diagonal holds f[x[n-1]], f[x[n-2], x[n-1]], ..., f[x[0], ..., x[n-1]]
+        let mut diagonal = Vec::with_capacity(self.diagonal.len() + 1);
+        diagonal.push(y);
+        for (&d, &xj) in self.diagonal.iter()
+            .zip(self.nodes.iter().rev())
+        {
+            let dx = x - xj;
+            if dx.is_zero()
+            {
+                return Err(InterpolatePolynomialError::DuplicateNode)
+            }
+            let last = diagonal[diagonal.len() - 1];
+            diagonal.push((last - d)/dx);
+        }
+
+        self.coefficients.push(diagonal[diagonal.len() - 1]);
+        self.nodes.push(x);
+        self.diagonal = diagonal;
+        Ok(())
+    }
+
+    /// Expands the polynomial into the monomial basis
+    pub fn to_polynomial(&self) -> Vec<C>
+    {
+        let mut p: Vec<C> = vec![];
+        for (&c, &x) in self.coefficients.iter()
+            .zip(self.nodes.iter())
+            .rev()
+        {
+            p.insert(0, C::zero());
+            for i in 0..p.len() - 1
+            {
+                p[i] = p[i] - x*p[i + 1];
+            }
+            p[0] = p[0] + c;
+        }
+        p
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> Default for NewtonPolynomial<C>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> Polynomial<C> for &NewtonPolynomial<C>
+where
+    C: Zero + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Copy
+{
+    type Y = C;
+
+    fn evaluate_as_polynomial(self, x: C) -> Self::Y
+    {
+        self.coefficients.iter()
+            .zip(self.nodes.iter())
+            .rev()
+            .fold(C::zero(), |y, (&c, &xk)| y*(x - xk) + c)
+    }
+}
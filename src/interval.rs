@@ -0,0 +1,401 @@
+use core::{fmt::Display, ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign}};
+
+use num_traits::Float;
+
+use super::*;
+
+/// Floating point numbers which can step to their neighbouring representable values, used to round the bounds of an [Interval](Interval) outward
+pub trait IntervalFloat: Float + Default
+{
+    /// The least representable number greater than `self`
+    fn next_up(self) -> Self;
+
+    /// The greatest representable number less than `self`
+    fn next_down(self) -> Self;
+}
+
+macro_rules! impl_interval_float {
+    ($($float:ty),*) => {
+        $(
+            impl IntervalFloat for $float
+            {
+                fn next_up(self) -> Self
+                {
+                    <$float>::next_up(self)
+                }
+
+                fn next_down(self) -> Self
+                {
+                    <$float>::next_down(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_interval_float!(f32, f64);
+
+/// A closed interval `[lo, hi]` of floating point numbers, with outward rounded arithmetic
+/// 
+/// Every operation rounds the lower bound of its result down and the upper bound up, unless it is exact, so the result always contains every value the operation can take on the operands,
+/// in exact arithmetic, as long as nothing overflows. Nonzero products and quotients near the subnormal range are always rounded outward, since their rounding errors may have underflowed.
+/// Polynomials can therefore be evaluated over an interval with [evaluate_as_polynomial](Polynomial::evaluate_as_polynomial)
+/// and [evaluate_as_polynomial_nd](PolynomialNd::evaluate_as_polynomial_nd), which yields a rigorous enclosure of the range of the polynomial on the interval.
+/// Tighter enclosures are found with [IntervalPolynomial](IntervalPolynomial).
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use polynomial_ops::*;
+/// 
+/// let x = Interval::new(1.0, 2.0);
+/// 
+/// assert_eq!(x - x, Interval::new(-1.0, 1.0));
+/// assert_eq!(x*x, Interval::new(1.0, 4.0));
+/// 
+/// // 1 - 3x + x^3
+/// let y = [1.0, -3.0, 0.0, 1.0].evaluate_as_polynomial(x);
+/// assert!(y.contains(-1.0) && y.contains(3.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Interval<F>
+{
+    lo: F,
+    hi: F
+}
+
+impl<F> Interval<F>
+where
+    F: IntervalFloat
+{
+    /// Creates the interval `[lo, hi]`
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `lo` is greater than `hi`, or either bound is NaN.
+    pub fn new(lo: F, hi: F) -> Self
+    {
+        assert!(lo <= hi, "the lower bound of an interval must not be greater than the upper bound");
+        Self {
+            lo,
+            hi
+        }
+    }
+
+    /// Creates the interval containing only `x`
+    pub fn point(x: F) -> Self
+    {
+        Self::new(x, x)
+    }
+
+    pub fn lo(self) -> F
+    {
+        self.lo
+    }
+
+    pub fn hi(self) -> F
+    {
+        self.hi
+    }
+
+    /// A point of the interval, close to its midpoint
+    pub fn mid(self) -> F
+    {
+        let two = F::one() + F::one();
+        (self.lo/two + self.hi/two).max(self.lo)
+            .min(self.hi)
+    }
+
+    /// An upper bound on the width of the interval
+    pub fn width(self) -> F
+    {
+        round_up(two_sum(self.hi, -self.lo))
+    }
+
+    /// An upper bound on the absolute values of the interval
+    pub fn mag(self) -> F
+    {
+        self.lo.abs()
+            .max(self.hi.abs())
+    }
+
+    pub fn contains(self, x: F) -> bool
+    {
+        self.lo <= x && x <= self.hi
+    }
+
+    pub fn is_subset_of(self, rhs: Self) -> bool
+    {
+        rhs.lo <= self.lo && self.hi <= rhs.hi
+    }
+
+    /// The smallest interval containing both intervals
+    pub fn hull(self, rhs: Self) -> Self
+    {
+        Self {
+            lo: self.lo.min(rhs.lo),
+            hi: self.hi.max(rhs.hi)
+        }
+    }
+
+    /// The interval of the values in both intervals, if they overlap
+    pub fn intersection(self, rhs: Self) -> Option<Self>
+    {
+        let lo = self.lo.max(rhs.lo);
+        let hi = self.hi.min(rhs.hi);
+        if lo <= hi
+        {
+            Some(Self {
+                lo,
+                hi
+            })
+        }
+        else
+        {
+            None
+        }
+    }
+
+    /// The interval between the values of an operation rounded down and up
+    fn rounded_outward(lo: (F, F), hi: (F, F)) -> Self
+    {
+        Self {
+            lo: round_down(lo),
+            hi: round_up(hi)
+        }
+    }
+
+    /// The outward rounded hull of four values
+    fn hull_of(y: [(F, F); 4]) -> Self
+    {
+        let [y0, y1, y2, y3] = y.map(round_down);
+        let lo = y0.min(y1).min(y2).min(y3);
+        let [y0, y1, y2, y3] = y.map(round_up);
+        let hi = y0.max(y1).max(y2).max(y3);
+        Self {
+            lo,
+            hi
+        }
+    }
+}
+
+/// Whether a nonzero product or quotient `y` is so small that its rounding error may have underflowed to zero
+fn may_underflow<F>(y: F) -> bool
+where
+    F: Float
+{
+    y.abs() < F::min_positive_value()/F::epsilon()
+}
+
+/// The rounded product `a*b = p`, and the error `a*b - p`, which is NaN if it may have underflowed
+fn mul_exact<F>(a: F, b: F) -> (F, F)
+where
+    F: Float
+{
+    let (p, e) = two_product(a, b);
+    if !a.is_zero() && !b.is_zero() && may_underflow(p) {(p, F::nan())} else {(p, e)}
+}
+
+/// The rounded quotient `a/b = q`, and a value with the sign of the error `a/b - q`, which is NaN if it may have underflowed
+fn div_exact<F>(a: F, b: F) -> (F, F)
+where
+    F: Float
+{
+    let q = a/b;
+    if !a.is_zero() && may_underflow(q) {(q, F::nan())} else {(q, (-q).mul_add(b, a)*b.signum())}
+}
+
+/// The greatest representable number not greater than the exact value `y + e`, where a NaN error of a finite value is unknown
+fn round_down<F>((y, e): (F, F)) -> F
+where
+    F: IntervalFloat
+{
+    if e < F::zero() || e.is_nan() && y.is_finite() {y.next_down()} else {y}
+}
+
+/// The least representable number not less than the exact value `y + e`, where a NaN error of a finite value is unknown
+fn round_up<F>((y, e): (F, F)) -> F
+where
+    F: IntervalFloat
+{
+    if e > F::zero() || e.is_nan() && y.is_finite() {y.next_up()} else {y}
+}
+
+impl<F> From<F> for Interval<F>
+where
+    F: IntervalFloat
+{
+    fn from(x: F) -> Self
+    {
+        Self::point(x)
+    }
+}
+
+impl<F> Display for Interval<F>
+where
+    F: Display
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl<F> Add for Interval<F>
+where
+    F: IntervalFloat
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output
+    {
+        Self::rounded_outward(two_sum(self.lo, rhs.lo), two_sum(self.hi, rhs.hi))
+    }
+}
+
+impl<F> Sub for Interval<F>
+where
+    F: IntervalFloat
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output
+    {
+        Self::rounded_outward(two_sum(self.lo, -rhs.hi), two_sum(self.hi, -rhs.lo))
+    }
+}
+
+impl<F> Mul for Interval<F>
+where
+    F: IntervalFloat
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output
+    {
+        Self::hull_of([
+            mul_exact(self.lo, rhs.lo),
+            mul_exact(self.lo, rhs.hi),
+            mul_exact(self.hi, rhs.lo),
+            mul_exact(self.hi, rhs.hi)
+        ])
+    }
+}
+
+impl<F> Div for Interval<F>
+where
+    F: IntervalFloat
+{
+    type Output = Self;
+
+    /// Divides two intervals, yielding the whole real line if the divisor contains zero
+    fn div(self, rhs: Self) -> Self::Output
+    {
+        if rhs.contains(F::zero())
+        {
+            return Self {
+                lo: F::neg_infinity(),
+                hi: F::infinity()
+            }
+        }
+        Self::hull_of([
+            div_exact(self.lo, rhs.lo),
+            div_exact(self.lo, rhs.hi),
+            div_exact(self.hi, rhs.lo),
+            div_exact(self.hi, rhs.hi)
+        ])
+    }
+}
+
+impl<F> Neg for Interval<F>
+where
+    F: IntervalFloat
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output
+    {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo
+        }
+    }
+}
+
+impl<F> Add<F> for Interval<F>
+where
+    F: IntervalFloat
+{
+    type Output = Self;
+
+    fn add(self, rhs: F) -> Self::Output
+    {
+        self + Self::point(rhs)
+    }
+}
+
+impl<F> Sub<F> for Interval<F>
+where
+    F: IntervalFloat
+{
+    type Output = Self;
+
+    fn sub(self, rhs: F) -> Self::Output
+    {
+        self - Self::point(rhs)
+    }
+}
+
+impl<F> Mul<F> for Interval<F>
+where
+    F: IntervalFloat
+{
+    type Output = Self;
+
+    fn mul(self, rhs: F) -> Self::Output
+    {
+        self*Self::point(rhs)
+    }
+}
+
+impl<F> Div<F> for Interval<F>
+where
+    F: IntervalFloat
+{
+    type Output = Self;
+
+    fn div(self, rhs: F) -> Self::Output
+    {
+        self/Self::point(rhs)
+    }
+}
+
+impl<F> AddAssign for Interval<F>
+where
+    F: IntervalFloat
+{
+    fn add_assign(&mut self, rhs: Self)
+    {
+        *self = *self + rhs
+    }
+}
+
+impl<F> SubAssign for Interval<F>
+where
+    F: IntervalFloat
+{
+    fn sub_assign(&mut self, rhs: Self)
+    {
+        *self = *self - rhs
+    }
+}
+
+impl<F> MulAssign for Interval<F>
+where
+    F: IntervalFloat
+{
+    fn mul_assign(&mut self, rhs: Self)
+    {
+        *self = *self*rhs
+    }
+}
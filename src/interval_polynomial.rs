@@ -0,0 +1,178 @@
+use core::alloc::Allocator;
+
+use super::*;
+
+pub trait IntervalPolynomial<F>
+{
+    /// Encloses the range of a polynomial on an interval using the mean value form
+    /// 
+    /// By the mean value theorem, `p(X)` is contained in `p(m) + p'(X)(X - m)` for any point `m` of `X`, where the midpoint is used.
+    /// The width of this enclosure shrinks quadratically with the width of the interval, while the natural enclosure from evaluating
+    /// the polynomial with [evaluate_as_polynomial](Polynomial::evaluate_as_polynomial) only shrinks linearly.
+    /// The intersection of both enclosures is yielded, so it is never wider than the natural enclosure.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // 1 - 3x + x^3, which takes the values [-1, -0.969] on [0.9, 1.1]
+    /// let p = [1.0, -3.0, 0.0, 1.0];
+    /// let x = Interval::new(0.9, 1.1);
+    /// 
+    /// let natural = p.evaluate_as_polynomial(x);
+    /// let y = p.evaluate_interval_mean_value(x);
+    /// 
+    /// assert!(y.contains(-1.0) && y.contains(-0.969));
+    /// assert!(y.width() < natural.width());
+    /// ```
+    fn evaluate_interval_mean_value(self, x: Interval<F>) -> Interval<F>;
+
+    /// Encloses the range of a polynomial on an interval using its coefficients in the Bernstein basis of the interval
+    /// 
+    /// A polynomial of degree `n` on `[a, b]` is written as a sum of the Bernstein polynomials `C(n, i)t^i(1 - t)^(n - i)` of `t = (x - a)/(b - a)`,
+    /// which are nonnegative and sum to one, so its range lies between the least and greatest Bernstein coefficient. The endpoints of this enclosure are
+    /// exact, up to rounding, whenever the extremes of the polynomial are at the endpoints of the interval. All coefficients are computed in interval arithmetic,
+    /// and the intersection with the natural enclosure is yielded.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 1)^2, which takes the values [0, 1] on [0, 2]
+    /// let p = [1.0, -2.0, 1.0];
+    /// let x = Interval::new(0.0, 2.0);
+    /// 
+    /// let y = p.evaluate_interval_bernstein(x);
+    /// 
+    /// assert!(y.contains(0.0) && y.contains(1.0));
+    /// assert_eq!(y, Interval::new(-1.0, 1.0));
+    /// assert_eq!(p.evaluate_as_polynomial(x), Interval::new(-3.0, 1.0));
+    /// ```
+    #[cfg(feature = "std")]
+    fn evaluate_interval_bernstein(self, x: Interval<F>) -> Interval<F>;
+}
+
+fn evaluate_interval_mean_value<F>(polynomial: &[F], x: Interval<F>) -> Interval<F>
+where
+    F: IntervalFloat
+{
+    let natural = polynomial.evaluate_as_polynomial(x);
+
+    let m = x.mid();
+    let derivative = polynomial.iter()
+        .enumerate()
+        .skip(1)
+        .rev()
+        .fold(Interval::default(), |y, (k, &c)| y*x + Interval::point(c)*F::from(k).unwrap());
+    let mean_value = polynomial.evaluate_as_polynomial(Interval::point(m)) + derivative*(x - m);
+
+    natural.intersection(mean_value)
+        .unwrap_or(natural)
+}
+
+#[cfg(feature = "std")]
+fn evaluate_interval_bernstein<F>(polynomial: &[F], x: Interval<F>) -> Interval<F>
+where
+    F: IntervalFloat
+{
+    let Some((&lead, rest)) = polynomial.split_last()
+    else
+    {
+        return Interval::default()
+    };
+    let n = rest.len();
+
+    // The coefficients of p(a + wt), where a = lo and w = hi - lo
+    let a = Interval::point(x.lo());
+    let w = Interval::point(x.hi()) - a;
+    let mut q = vec![Interval::default(); n + 1];
+    q[0] = Interval::point(lead);
+    for (i, &c) in rest.iter()
+        .rev()
+        .enumerate()
+    {
+        for j in (0..=i).rev()
+        {
+            q[j + 1] = q[j + 1] + q[j]*w;
+            q[j] *= a;
+        }
+        q[0] += Interval::point(c);
+    }
+
+    // b_i = sum_j C(i, j)/C(n, j) q_j
+    let mut binomial = Interval::point(F::one());
+    for (j, q) in q.iter_mut()
+        .enumerate()
+    {
+        if j > 0
+        {
+            binomial = binomial*F::from(n + 1 - j).unwrap()/F::from(j).unwrap();
+        }
+        *q = *q/binomial;
+    }
+    for k in 1..=n
+    {
+        for i in (k..=n).rev()
+        {
+            q[i] = q[i] + q[i - 1];
+        }
+    }
+
+    let bernstein = q.into_iter()
+        .reduce(Interval::hull)
+        .unwrap();
+    let natural = polynomial.evaluate_as_polynomial(x);
+    natural.intersection(bernstein)
+        .unwrap_or(natural)
+}
+
+impl<F> IntervalPolynomial<F> for &[F]
+where
+    F: IntervalFloat
+{
+    fn evaluate_interval_mean_value(self, x: Interval<F>) -> Interval<F>
+    {
+        evaluate_interval_mean_value(self, x)
+    }
+
+    #[cfg(feature = "std")]
+    fn evaluate_interval_bernstein(self, x: Interval<F>) -> Interval<F>
+    {
+        evaluate_interval_bernstein(self, x)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, A> IntervalPolynomial<F> for Vec<F, A>
+where
+    A: Allocator,
+    F: IntervalFloat
+{
+    fn evaluate_interval_mean_value(self, x: Interval<F>) -> Interval<F>
+    {
+        self.as_slice().evaluate_interval_mean_value(x)
+    }
+
+    fn evaluate_interval_bernstein(self, x: Interval<F>) -> Interval<F>
+    {
+        self.as_slice().evaluate_interval_bernstein(x)
+    }
+}
+
+impl<F, const N: usize> IntervalPolynomial<F> for [F; N]
+where
+    F: IntervalFloat
+{
+    fn evaluate_interval_mean_value(self, x: Interval<F>) -> Interval<F>
+    {
+        self.as_slice().evaluate_interval_mean_value(x)
+    }
+
+    #[cfg(feature = "std")]
+    fn evaluate_interval_bernstein(self, x: Interval<F>) -> Interval<F>
+    {
+        self.as_slice().evaluate_interval_bernstein(x)
+    }
+}
@@ -0,0 +1,90 @@
+use core::ops::{Add, Mul, Sub};
+
+use super::*;
+
+/// The shortest length of both polynomials for which [mul_polynomial](MulPolynomial::mul_polynomial) uses Karatsuba's algorithm instead of direct convolution
+/// 
+/// Karatsuba's algorithm needs three half-size products instead of four, so the product of polynomials of length `n` takes `O(n^1.585)` operations instead of `O(n^2)`.
+/// Only additions, subtractions and multiplications of the coefficients are used, so the product is exact for integers and rationals.
+/// Much longer polynomials are split into pieces the length of the shorter one.
+/// 
+/// With the benchmarks in `benches/mul_polynomial.rs`, Karatsuba's algorithm breaks even with direct convolution at about 48 coefficients of `i64` or `f64`,
+/// and is about twice as fast at 512 coefficients. Recursing further down than this is slower at every length.
+pub const KARATSUBA_MULTIPLICATION_THRESHOLD: usize = 48;
+
+impl<C> MulCoefficients<C> for C
+where
+    C: Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Default + Copy
+{
+    default fn mul_coefficients(lhs: &[C], rhs: &[C]) -> Vec<C>
+    {
+        if lhs.len().min(rhs.len()) < KARATSUBA_MULTIPLICATION_THRESHOLD
+        {
+            return convolve_polynomial(lhs, rhs)
+        }
+        karatsuba_mul_polynomial(lhs, rhs)
+    }
+}
+
+fn add_at<C>(y: &mut [C], x: &[C], offset: usize)
+where
+    C: Add<Output = C> + Copy
+{
+    for (y, &x) in y[offset..].iter_mut()
+        .zip(x.iter())
+    {
+        *y = *y + x;
+    }
+}
+
+fn sum<C>(lhs: &[C], rhs: &[C]) -> Vec<C>
+where
+    C: Add<Output = C> + Copy
+{
+    let (long, short) = if lhs.len() >= rhs.len() {(lhs, rhs)} else {(rhs, lhs)};
+    let mut y = long.to_vec();
+    add_at(&mut y, short, 0);
+    y
+}
+
+/// Karatsuba's algorithm for two nonempty polynomials
+pub(crate) fn karatsuba_mul_polynomial<C>(lhs: &[C], rhs: &[C]) -> Vec<C>
+where
+    C: Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Default + Copy
+{
+    let (a, b) = if lhs.len() >= rhs.len() {(lhs, rhs)} else {(rhs, lhs)};
+    let (n, m) = (a.len(), b.len());
+    if m < KARATSUBA_MULTIPLICATION_THRESHOLD
+    {
+        return convolve_polynomial(a, b)
+    }
+
+    let mut y = vec![C::default(); n + m - 1];
+    if n >= 2*m
+    {
+        for (i, a) in a.chunks(m)
+            .enumerate()
+        {
+            add_at(&mut y, &karatsuba_mul_polynomial(a, b), i*m);
+        }
+        return y
+    }
+
+    // a = a0 + x^h a1 and b = b0 + x^h b1, where b1 is nonempty since h < m
+    let h = n/2;
+    let (a0, a1) = a.split_at(h);
+    let (b0, b1) = b.split_at(h);
+    let z0 = karatsuba_mul_polynomial(a0, b0);
+    let z2 = karatsuba_mul_polynomial(a1, b1);
+    let mut z1 = karatsuba_mul_polynomial(&sum(a0, a1), &sum(b0, b1));
+    for (k, z1) in z1.iter_mut()
+        .enumerate()
+    {
+        *z1 = *z1 - z0.get(k).copied().unwrap_or_default() - z2.get(k).copied().unwrap_or_default();
+    }
+
+    add_at(&mut y, &z0, 0);
+    add_at(&mut y, &z1, h);
+    add_at(&mut y, &z2, 2*h);
+    y
+}
@@ -6,15 +6,50 @@
 
 #![feature(generic_const_exprs)]
 #![feature(const_closures)]
+#![feature(portable_simd)]
+#![feature(unboxed_closures)]
+#![feature(fn_traits)]
+#![feature(specialization)]
 
 moddef::moddef!(
     flat(pub) mod {
+        add_polynomial,
         chebyshev_polynomial,
+        compensated_polynomial,
+        compose_polynomial,
+        derive_polynomial,
+        descartes_polynomial for cfg(feature = "std"),
+        div_rem_polynomial,
+        factor_polynomial for cfg(feature = "std"),
+        fft_polynomial for cfg(feature = "std"),
+        find_roots_polynomial,
+        from_roots_polynomial,
+        gcd_polynomial for cfg(feature = "std"),
+        integrate_polynomial,
+        interpolate_polynomial,
+        interval_polynomial,
+        interval,
+        karatsuba_polynomial for cfg(feature = "std"),
+        mod_int,
+        modular_polynomial for cfg(feature = "std"),
         mul_polynomial,
+        multipoint_polynomial for cfg(feature = "std"),
+        neg_polynomial,
+        par_polynomial for cfg(feature = "rayon"),
         plot for cfg(test),
+        poly_vec for cfg(feature = "std"),
+        poly,
+        polynomial_derivatives,
         polynomial_nd,
+        polynomial_roots,
         polynomial,
-        product_polynomial
+        product_polynomial,
+        resultant_polynomial for cfg(feature = "std"),
+        root_bound_polynomial,
+        simd_polynomial,
+        square_free_polynomial for cfg(feature = "std"),
+        sturm_sequence for cfg(feature = "std"),
+        sub_polynomial
     }
 );
 
@@ -39,6 +74,280 @@ mod tests {
         println!("{:?}", q);
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn add_sub()
+    {
+        const P: [i32; 2] = [1, 2];
+        const Q: [i32; 4] = [3, 0, 0, 4];
+
+        let r: [i32; 4] = P.add_polynomial(Q);
+        assert_eq!(r, [4, 2, 0, 4]);
+        assert_eq!(r.sub_polynomial(Q), [1, 2, 0, 0]);
+        assert_eq!(P.sub_polynomial(Q), Q.sub_polynomial(P).neg_polynomial());
+
+        let q = vec![3, 0, 0, 4];
+        assert_eq!(P.as_slice().add_polynomial(q.clone()), r.to_vec());
+        assert_eq!(q.clone().sub_polynomial(P), Q.sub_polynomial(P).to_vec());
+        assert_eq!(q.neg_polynomial(), Q.neg_polynomial().to_vec());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn div_rem()
+    {
+        let p: [i32; 4] = [-1, 1].mul_polynomial([-2, 1]).mul_polynomial([3, 1]);
+
+        let (q, r): ([i32; 3], [i32; 1]) = p.div_rem_polynomial([-2, 1]).unwrap();
+        assert_eq!(q, [-1, 1].mul_polynomial([3, 1]));
+        assert_eq!(r, [0]);
+
+        let (q, r) = p.to_vec().div_rem_polynomial(vec![1, 1, 0, 0]).unwrap();
+        assert_eq!(q.mul_polynomial([1, 1]).add_polynomial(r), p.to_vec());
+
+        assert_eq!(p.as_slice().div_rem_polynomial(vec![0, 0]), Err(DivRemPolynomialError::ZeroLeadingCoefficient));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn poly()
+    {
+        let p = Poly([-1.0, 1.0]);
+        let q = Poly([-2.0, 1.0]);
+        let r = Poly([3.0, 1.0]);
+
+        let pqr: Poly<f64, 4> = p*q*r;
+        assert_eq!(pqr/q, p*r);
+        assert_eq!(pqr%q, Poly([0.0]));
+        assert_eq!(pqr(2.0), 0.0);
+        assert_eq!(pqr(0.0), pqr[0]);
+
+        let pqr_vec = PolyVec(pqr.0.to_vec());
+        assert_eq!(pqr_vec.clone()/PolyVec(q.0.to_vec()), PolyVec((p*r).0.to_vec()));
+        assert_eq!(pqr_vec(-3.0), 0.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gcd()
+    {
+        type T = i128;
+
+        // T6(x) = T3(T2(x)), so T2 divides T6
+        let t2: [T; 3] = ChebyshevPolynomial::new_of_first_kind(2).try_into().ok().unwrap();
+        let t6: [T; 7] = ChebyshevPolynomial::new_of_first_kind(6).try_into().ok().unwrap();
+        assert_eq!(t6.subresultant_gcd_polynomial(t2), t2.to_vec());
+
+        // The roots of T4 and U3 interlace, so they share no factors
+        let t4: [T; 5] = ChebyshevPolynomial::new_of_first_kind(4).try_into().ok().unwrap();
+        let u3: [T; 4] = ChebyshevPolynomial::new_of_second_kind(3).try_into().ok().unwrap();
+        assert_eq!(t4.subresultant_gcd_polynomial(u3), vec![1]);
+    }
+
+    #[test]
+    fn compose()
+    {
+        type T = i128;
+
+        // Tm(Tn(x)) = Tmn(x)
+        let t2: [T; 3] = ChebyshevPolynomial::new_of_first_kind(2).try_into().ok().unwrap();
+        let t3: [T; 4] = ChebyshevPolynomial::new_of_first_kind(3).try_into().ok().unwrap();
+        let t6: [T; 7] = ChebyshevPolynomial::new_of_first_kind(6).try_into().ok().unwrap();
+        assert_eq!(t2.compose_polynomial(t3), t6);
+        assert_eq!(t3.compose_polynomial(t2), t6);
+    }
+
+    #[test]
+    fn roots()
+    {
+        use num_complex::Complex;
+
+        // The roots in any order, up to a tolerance relative to their magnitude, where infinite roots must match exactly
+        fn assert_roots<const N: usize>(roots: [Complex<f64>; N], expected: [Complex<f64>; N], tolerance: f64)
+        {
+            let sort = |mut z: [Complex<f64>; N]| {
+                z.sort_unstable_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+                z
+            };
+            for (z, w) in sort(roots).into_iter().zip(sort(expected))
+            {
+                assert!(z == w || (z - w).norm() <= tolerance*w.norm().max(1.0), "{:?} != {:?}", roots, expected);
+            }
+        }
+        let re = |x: f64| Complex::new(x, 0.0);
+        let inf = re(f64::INFINITY);
+        let i = Complex::i();
+
+        // Simple roots are accurate to a few rounding errors, while repeated roots are only well-conditioned to about the square root of the precision
+        const SIMPLE: f64 = 1e-12;
+        const REPEATED: f64 = 1e-7;
+
+        // Quadratic formula
+        assert_roots([2.0, -3.0, 1.0].roots(), [re(1.0), re(2.0)], SIMPLE);
+        assert_roots([5.0, -4.0, 1.0].roots(), [re(2.0) - i, re(2.0) + i], SIMPLE);
+        assert_roots([9.0, -6.0, 1.0].roots(), [re(3.0); 2], REPEATED);
+        assert_roots([-1.0, 1.0, 1e-12].roots(), [re(-1e12 - 1.0), re(1.0 - 1e-12)], SIMPLE);
+        assert_roots([-1.0, 1.0, 0.0].roots(), [inf, re(1.0)], SIMPLE);
+
+        // Cardano's formula with one real root, and the trigonometric method with three
+        assert_roots([-1.0, 1.0, -1.0, 1.0].roots(), [re(1.0), -i, i], SIMPLE);
+        assert_roots([-6.0, 11.0, -6.0, 1.0].roots(), [re(1.0), re(2.0), re(3.0)], SIMPLE);
+        assert_roots([2.0, -3.0, 0.0, 1.0].roots(), [re(-2.0), re(1.0), re(1.0)], REPEATED);
+        assert_roots([-8.0, 12.0, -6.0, 1.0].roots(), [re(2.0); 3], REPEATED);
+        assert_roots([2.0, -3.0, 1.0, 1e-10].roots(), [re(-1e10 - 3.0), re(1.0 + 1e-10), re(2.0 - 8e-10)], SIMPLE);
+        assert_roots([2.0, -3.0, 1.0, 0.0].roots(), [inf, re(1.0), re(2.0)], SIMPLE);
+
+        // Ferrari's method, and biquadratics
+        assert_roots([24.0, -50.0, 35.0, -10.0, 1.0].roots(), [re(1.0), re(2.0), re(3.0), re(4.0)], SIMPLE);
+        assert_roots([1.0, -2.0, 2.0, -2.0, 1.0].roots(), [re(1.0), re(1.0), -i, i], REPEATED);
+        assert_roots([4.0, -12.0, 13.0, -6.0, 1.0].roots(), [re(1.0), re(1.0), re(2.0), re(2.0)], REPEATED);
+        assert_roots([1.0, -4.0, 6.0, -4.0, 1.0].roots(), [re(1.0); 4], REPEATED);
+        assert_roots(
+            [-6.0, 11.0, -6.0, 1.0, 1e-10].roots(),
+            [re(-1e10 - 6.0), re(1.0 - 5e-11), re(2.0 + 1.6e-9), re(3.0 - 4.05e-9)],
+            SIMPLE
+        );
+        assert_roots([-6.0, 11.0, -6.0, 1.0, 0.0].roots(), [inf, re(1.0), re(2.0), re(3.0)], SIMPLE);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fft()
+    {
+        // A safe prime, whose primitive root would take far too long to find by trial division at compile time,
+        // and which has no roots of unity of the transform length, so it falls back to direct convolution
+        type M = ModInt<4611686018427377339>;
+
+        let p: Vec<M> = (0..100u64).map(|k| M::new(k*k + 1)).collect();
+        let q: Vec<M> = (0..100u64).map(|k| M::new(u64::MAX - k)).collect();
+        let pq = p.as_slice().mul_polynomial_fft(q.as_slice());
+        for x in (0..10u64).map(M::new)
+        {
+            assert_eq!(
+                pq.as_slice().evaluate_as_polynomial(x),
+                p.as_slice().evaluate_as_polynomial(x)*q.as_slice().evaluate_as_polynomial(x)
+            );
+        }
+
+        assert_eq!(ModInt::<NTT_PRIME>::primitive_root().value(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn multipoint()
+    {
+        use num_complex::Complex;
+
+        type M = ModInt<NTT_PRIME>;
+
+        // Long enough for the subproduct tree, with a batch of points that is not a multiple of the leaf length
+        let p: Vec<M> = (0..300u64).map(|k| M::new(k*k + 1)).collect();
+        let x: Vec<M> = (0..700u64).map(|k| M::new(3*k + 2)).collect();
+        assert_eq!(
+            p.evaluate_many(&x),
+            x.iter().map(|&x| p.as_slice().evaluate_as_polynomial(x)).collect::<Vec<_>>()
+        );
+
+        // Points which are roots of the polynomial
+        let x: Vec<M> = (0..MULTIPOINT_EVALUATION_THRESHOLD as u64).map(M::new).collect();
+        let p = x.iter()
+            .fold(vec![M::new(1)], |p, &x| p.mul_polynomial(vec![-x, M::new(1)]));
+        assert!(p.evaluate_many(&x).into_iter().all(|y| y == M::new(0)));
+
+        // The products of the subproduct tree would overflow, even though every value fits
+        let p: Vec<i64> = (0..300).map(|k| k % 3 - 1).collect();
+        let x: Vec<i64> = (0..300).map(|k| k % 3 - 1).collect();
+        assert_eq!(
+            p.evaluate_many(&x),
+            x.iter().map(|&x| p.as_slice().evaluate_as_polynomial(x)).collect::<Vec<_>>()
+        );
+
+        // Complex floating point numbers must not use the numerically unstable subproduct tree either
+        let p: Vec<Complex<f64>> = (0..300).map(|k| Complex::new((k % 7) as f64 - 3.0, (k % 5) as f64 - 2.0)).collect();
+        let x: Vec<Complex<f64>> = (0..300).map(|k| Complex::from_polar(1.0, k as f64)).collect();
+        assert_eq!(
+            p.evaluate_many(&x),
+            x.iter().map(|&x| p.as_slice().evaluate_as_polynomial(x)).collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn descartes()
+    {
+        use num_rational::BigRational;
+
+        // Every bisection of T30 adds about 30 bits to its coefficients, which would overflow an i128
+        const N: usize = 30;
+        let t: Vec<i128> = ChebyshevPolynomial::new_of_first_kind(N).into();
+        let intervals = t.isolate_real_roots_exact();
+
+        assert_eq!(intervals.len(), N);
+        for (k, (a, b)) in (1..=N).rev().zip(intervals)
+        {
+            let root = BigRational::from_float(((2*k - 1) as f64*core::f64::consts::PI/(2*N) as f64).cos()).unwrap();
+            assert!(a < root && root < b);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn factor()
+    {
+        // Mignotte's bound for T36 needs a prime power far beyond 2^62 to lift its factors to, one for each odd divisor of 36
+        const N: usize = 36;
+        let t: Vec<i128> = ChebyshevPolynomial::new_of_first_kind(N).into();
+        let (content, factors) = t.clone().factor_polynomial();
+
+        assert_eq!(content, 1);
+        assert_eq!(factors.iter().map(|(f, e)| (f.len() - 1, *e)).collect::<Vec<_>>(), vec![(4, 1), (8, 1), (24, 1)]);
+        assert_eq!(
+            factors.into_iter().fold(vec![content], |p, (f, _)| p.mul_polynomial(f)),
+            t
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sturm()
+    {
+        use num_rational::{BigRational, Ratio};
+
+        type T = Ratio<i64>;
+
+        // (x + 3)(x + 1/2)(x - 1)^2(x - 4)^3, where the repeated roots must be counted once
+        let p = [-3, 1, 1, 4, 4, 4].map(T::from)
+            .into_iter()
+            .chain([T::new(-1, 2)])
+            .fold(vec![T::from(1)], |p, r| p.mul_polynomial(vec![-r, T::from(1)]));
+        let sturm = p.sturm_sequence();
+
+        assert_eq!(sturm.polynomials()[0].len(), 5);
+        assert_eq!(sturm.count_real_roots(), 4);
+        assert_eq!(sturm.count_real_roots_in(T::from(0), T::from(1)), 1);
+        assert_eq!(sturm.count_real_roots_in(T::from(1), T::from(4)), 1);
+
+        let intervals = sturm.isolate_real_roots().unwrap();
+        assert_eq!(intervals.len(), 4);
+        for (root, (a, b)) in [T::from(-3), T::new(-1, 2), T::from(1), T::from(4)].into_iter().zip(intervals)
+        {
+            assert!(a < root && root <= b);
+        }
+
+        // (x + 2)(x - 1)(x - 1 - 10^-12), whose close roots take about 40 bisections to separate, which would overflow a Ratio<i64>
+        let q = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+        let r = q(1_000_000_000_001, 1_000_000_000_000);
+        let p = vec![q(2, 1)*&r, q(-2, 1) - &r, q(1, 1) - &r, q(1, 1)];
+        let roots = [q(-2, 1), q(1, 1), r];
+
+        let intervals = p.isolate_real_roots().unwrap();
+        assert_eq!(intervals.len(), 3);
+        for (root, (a, b)) in roots.into_iter().zip(intervals)
+        {
+            assert!(a < root && root <= b);
+        }
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn eval_nd()
@@ -62,6 +371,67 @@ mod tests {
         println!("{:?}", xy.map_nd(|xy: [u128; 2]| A.evaluate_as_polynomial_nd(xy)))
     }
 
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_eval_nd()
+    {
+        use array__ops::ArrayNdOps;
+
+        const A: [[u128; 3]; 3] = [
+            [1, 2, 3],
+            [4, 5, 6],
+            [7, 8, 9]
+        ];
+
+        let xy: [[[u128; 2]; 3]; 3] = ArrayNdOps::fill_nd(|i| i.map2(const |i| i as u128));
+        let grid = xy.map_nd(|xy: [u128; 2]| A.evaluate_as_polynomial_nd(xy));
+
+        assert_eq!(A.par_evaluate_grid_nd([&[0u128, 1, 2][..], &[0, 1, 2][..]]), grid.concat());
+        assert_eq!(A.par_evaluate_many_nd(&xy.concat()), grid.concat());
+    }
+
+    #[test]
+    fn interval_underflow()
+    {
+        let tiny = f64::from_bits(1);
+
+        // The product underflows to zero, and so does its rounding error
+        let z = Interval::point(1e-200)*Interval::point(1e-200);
+        assert!(z.lo() <= 0.0 && 0.0 < z.hi());
+
+        let z = Interval::new(1e-200, 1e-190)*Interval::point(-1e-200);
+        assert!(z.lo() < 0.0 && 0.0 <= z.hi());
+
+        // The quotient is about 1.43 times the least subnormal, but is rounded down to it with a rounding error which underflows to zero
+        let z = Interval::point(tiny)/Interval::point(0.7);
+        assert!(z.lo() <= tiny && tiny < z.hi());
+
+        let z = Interval::point(-1e-300)/Interval::point(1e100);
+        assert!(z.lo() < 0.0 && 0.0 <= z.hi());
+    }
+
+    #[test]
+    fn interval_nd()
+    {
+        const A: [[f64; 2]; 2] = [
+            [1.0, -2.0],
+            [0.5, 3.0]
+        ];
+
+        let x = Interval::new(-1.0, 0.5);
+        let y = Interval::new(0.25, 2.0);
+        let z = A.evaluate_as_polynomial_nd([x, y]);
+
+        for i in 0..=10
+        {
+            for j in 0..=10
+            {
+                let xy = [x.lo() + 0.15*i as f64, y.lo() + 0.175*j as f64];
+                assert!(z.contains(A.evaluate_as_polynomial_nd(xy)));
+            }
+        }
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn it_works()
@@ -113,7 +483,7 @@ mod tests {
         
         const PLOT_TARGET: &str = "plots";
 
-        use crate::{ChebyshevPolynomial, Polynomial, PolynomialNd};
+        use crate::{ChebyshevPolynomial, Polynomial, PolynomialNd, RootBoundPolynomial};
         use array__ops::ArrayOps;
         use currying::Curry;
         use linspace::LinspaceArray;
@@ -127,15 +497,15 @@ mod tests {
             
             let cheb: [T; N] = ChebyshevPolynomial::new(KIND, ORDER).try_into().ok().unwrap();
             
-            const X0: T = 0.0;
-            const X1: T = 4.0;
+            let x1: T = cheb.root_bound();
+            let x0: T = -x1;
 
             const RESOLUTION: usize = 1024;
-            let x: [T; RESOLUTION] = (X0..X1).linspace_array();
+            let x: [T; RESOLUTION] = (x0..x1).linspace_array();
 
             let y: [T; RESOLUTION] = x.map2(Polynomial::evaluate_as_polynomial.curry(cheb));
             
-            let plot_title: &str = &format!("{ORDER}. order Chebyshev of the {KIND}. kind, x = {X0}..{X1}:");
+            let plot_title: &str = &format!("{ORDER}. order Chebyshev of the {KIND}. kind, x = {x0}..{x1}:");
             let plot_path: &str = &format!("{PLOT_TARGET}/chebyshev.png"); //&format!("{PLOT_TARGET}/chebyshev_k{KIND}_o{ORDER}_x{X0}_{X1}.png");
 
             plot_curve(plot_title, plot_path, x, y).unwrap()
@@ -150,15 +520,15 @@ mod tests {
             const POLYNOMIAL: [T; 6] = [1.0, -1.0, 0.0, 0.0, 0.0, 1.0];
             const ORDER: usize = POLYNOMIAL.len() + 1;
             
-            const X0: T = -5 as T;
-            const X1: T = 5 as T;
+            let x1: T = POLYNOMIAL.root_bound();
+            let x0: T = -x1;
 
             const RESOLUTION: usize = 1024;
-            let x: [T; RESOLUTION] = (X0..X1).linspace_array();
+            let x: [T; RESOLUTION] = (x0..x1).linspace_array();
 
             let y: [T; RESOLUTION] = x.map(Polynomial::evaluate_as_polynomial.curry(POLYNOMIAL));
             
-            let plot_title: &str = &format!("{ORDER}. order polynomial, x = {X0}..{X1}:");
+            let plot_title: &str = &format!("{ORDER}. order polynomial, x = {x0}..{x1}:");
             let plot_path: &str = &format!("{PLOT_TARGET}/polynomial.png"); //&format!("{PLOT_TARGET}/polynomial_{:?}_x{X0}_{X1}.png", POLYNOMIAL);
 
             plot_curve(plot_title, plot_path, x, y).unwrap()
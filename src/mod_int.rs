@@ -0,0 +1,250 @@
+use core::{fmt::Display, ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign}};
+
+use num_traits::{One, Zero};
+
+/// The prime `119*2^23 + 1`, which has roots of unity of every power of two order up to `2^23`, making it suited for number-theoretic transforms
+pub const NTT_PRIME: u64 = 998244353;
+
+/// An integer modulo `P`, which is expected to be a prime below `2^63`
+/// 
+/// The value is always kept as its least nonnegative residue. Division multiplies by the inverse, found by Fermat's little theorem,
+/// so it is only meaningful when `P` is prime and the divisor is nonzero.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use polynomial_ops::*;
+/// 
+/// type M = ModInt<7>;
+/// 
+/// assert_eq!(M::new(5) + M::new(4), M::new(2));
+/// assert_eq!(M::new(3)*M::new(5), M::new(1));
+/// assert_eq!(M::new(1)/M::new(3), M::new(5));
+/// assert_eq!((-M::new(1)).value(), 6);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModInt<const P: u64>(u64);
+
+impl<const P: u64> ModInt<P>
+{
+    pub const fn new(value: u64) -> Self
+    {
+        Self(value % P)
+    }
+
+    pub const fn value(self) -> u64
+    {
+        self.0
+    }
+
+    pub const fn pow(self, mut e: u64) -> Self
+    {
+        let mut a = self.0;
+        let mut y = 1 % P;
+        while e > 0
+        {
+            if e % 2 == 1
+            {
+                y = ((y as u128*a as u128) % P as u128) as u64;
+            }
+            a = ((a as u128*a as u128) % P as u128) as u64;
+            e /= 2;
+        }
+        Self(y)
+    }
+
+    /// The multiplicative inverse, by Fermat's little theorem
+    pub const fn inv(self) -> Self
+    {
+        self.pow(P - 2)
+    }
+
+    /// The smallest generator of the multiplicative group
+    /// 
+    /// This is found at runtime by trial division of `P - 1`, and with the `std` feature only computed once for each modulus.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// assert_eq!(ModInt::<NTT_PRIME>::primitive_root().value(), 3);
+    /// assert_eq!(ModInt::<7>::primitive_root().value(), 3);
+    /// ```
+    pub fn primitive_root() -> Self
+    {
+        #[cfg(feature = "std")]
+        {
+            use std::{collections::BTreeMap, sync::Mutex};
+
+            // Statics are shared by every modulus, so the roots are keyed by it
+            static PRIMITIVE_ROOTS: Mutex<BTreeMap<u64, u64>> = Mutex::new(BTreeMap::new());
+
+            let mut roots = PRIMITIVE_ROOTS.lock()
+                .unwrap_or_else(|error| error.into_inner());
+            Self(*roots.entry(P)
+                .or_insert_with(|| Self::find_primitive_root().0)
+            )
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Self::find_primitive_root()
+        }
+    }
+
+    /// Finds the smallest generator of the multiplicative group, by trial division of `P - 1`
+    fn find_primitive_root() -> Self
+    {
+        // A 64-bit integer has at most 15 distinct prime factors
+        let mut factors = [0; 15];
+        let mut count = 0;
+        let mut n = P - 1;
+        let mut d = 2;
+        while d*d <= n
+        {
+            if n.is_multiple_of(d)
+            {
+                factors[count] = d;
+                count += 1;
+                while n.is_multiple_of(d)
+                {
+                    n /= d;
+                }
+            }
+            d += 1;
+        }
+        if n > 1
+        {
+            factors[count] = n;
+            count += 1;
+        }
+
+        let mut g = 2;
+        while g < P
+        {
+            let mut i = 0;
+            while i < count && Self::new(g).pow((P - 1)/factors[i]).0 != 1
+            {
+                i += 1;
+            }
+            if i == count
+            {
+                return Self::new(g)
+            }
+            g += 1;
+        }
+        Self(1 % P)
+    }
+}
+
+impl<const P: u64> From<u64> for ModInt<P>
+{
+    fn from(value: u64) -> Self
+    {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> Display for ModInt<P>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        write!(f, "{} (mod {})", self.0, P)
+    }
+}
+
+impl<const P: u64> Add for ModInt<P>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output
+    {
+        Self((self.0 + rhs.0) % P)
+    }
+}
+
+impl<const P: u64> Sub for ModInt<P>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output
+    {
+        Self((self.0 + P - rhs.0) % P)
+    }
+}
+
+impl<const P: u64> Mul for ModInt<P>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output
+    {
+        Self(((self.0 as u128*rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Div for ModInt<P>
+{
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output
+    {
+        self*rhs.inv()
+    }
+}
+
+impl<const P: u64> Neg for ModInt<P>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output
+    {
+        Self((P - self.0) % P)
+    }
+}
+
+impl<const P: u64> AddAssign for ModInt<P>
+{
+    fn add_assign(&mut self, rhs: Self)
+    {
+        *self = *self + rhs
+    }
+}
+
+impl<const P: u64> SubAssign for ModInt<P>
+{
+    fn sub_assign(&mut self, rhs: Self)
+    {
+        *self = *self - rhs
+    }
+}
+
+impl<const P: u64> MulAssign for ModInt<P>
+{
+    fn mul_assign(&mut self, rhs: Self)
+    {
+        *self = *self*rhs
+    }
+}
+
+impl<const P: u64> Zero for ModInt<P>
+{
+    fn zero() -> Self
+    {
+        Self(0)
+    }
+
+    fn is_zero(&self) -> bool
+    {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for ModInt<P>
+{
+    fn one() -> Self
+    {
+        Self(1 % P)
+    }
+}
@@ -0,0 +1,390 @@
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+
+use super::*;
+
+/// Primes just below `2^62`, so that products of two residues fit in a `u128` and sums of two residues fit in a `u64`
+pub(crate) const MODULAR_PRIMES: [u64; 4] = [
+    4611686018427387847,
+    4611686018427387817,
+    4611686018427387787,
+    4611686018427387761
+];
+
+pub(crate) fn mul_mod(a: u64, b: u64, m: u64) -> u64
+{
+    ((a as u128*b as u128) % m as u128) as u64
+}
+
+/// The inverse of a residue coprime to the modulus, by the extended euclidean algorithm
+/// 
+/// The modulus does not need to be prime, so this also inverts units modulo prime powers.
+pub(crate) fn inv_mod(a: u64, m: u64) -> u64
+{
+    let (mut r0, mut r1) = (m as i128, (a % m) as i128);
+    let (mut s0, mut s1) = (0i128, 1i128);
+    while r1 != 0
+    {
+        let q = r0/r1;
+        (r0, r1) = (r1, r0 - q*r1);
+        (s0, s1) = (s1, s0 - q*s1);
+    }
+    s0.rem_euclid(m as i128) as u64
+}
+
+/// Reduces every coefficient to its least nonnegative residue, and trims trailing zeros
+pub(crate) fn reduce_polynomial_mod(polynomial: &[i128], m: u64) -> Vec<u64>
+{
+    trim_polynomial(polynomial.iter()
+        .map(|c| c.rem_euclid(m as i128) as u64)
+        .collect()
+    )
+}
+
+/// Lifts every residue to the symmetric range `(-m/2, m/2]`
+pub(crate) fn lift_polynomial_mod(polynomial: &[u64], m: u64) -> Vec<i128>
+{
+    polynomial.iter()
+        .map(|&c| if c > m/2 {c as i128 - m as i128} else {c as i128})
+        .collect()
+}
+
+pub(crate) fn add_polynomial_mod(lhs: &[u64], rhs: &[u64], m: u64) -> Vec<u64>
+{
+    trim_polynomial((0..lhs.len().max(rhs.len()))
+        .map(|k| (lhs.get(k).copied().unwrap_or(0) + rhs.get(k).copied().unwrap_or(0)) % m)
+        .collect()
+    )
+}
+
+pub(crate) fn sub_polynomial_mod(lhs: &[u64], rhs: &[u64], m: u64) -> Vec<u64>
+{
+    trim_polynomial((0..lhs.len().max(rhs.len()))
+        .map(|k| (lhs.get(k).copied().unwrap_or(0) + m - rhs.get(k).copied().unwrap_or(0)) % m)
+        .collect()
+    )
+}
+
+pub(crate) fn mul_polynomial_mod(lhs: &[u64], rhs: &[u64], m: u64) -> Vec<u64>
+{
+    if lhs.is_empty() || rhs.is_empty()
+    {
+        return vec![]
+    }
+    let mut y = vec![0; lhs.len() + rhs.len() - 1];
+    for (i, &a) in lhs.iter()
+        .enumerate()
+    {
+        for (y, &b) in y[i..].iter_mut()
+            .zip(rhs.iter())
+        {
+            *y = (*y + mul_mod(a, b, m)) % m;
+        }
+    }
+    trim_polynomial(y)
+}
+
+pub(crate) fn monic_polynomial_mod(mut polynomial: Vec<u64>, m: u64) -> Vec<u64>
+{
+    if let Some(&lead) = polynomial.last()
+    {
+        let inv = inv_mod(lead, m);
+        for c in polynomial.iter_mut()
+        {
+            *c = mul_mod(*c, inv, m);
+        }
+    }
+    polynomial
+}
+
+/// Long division modulo a prime, where `rhs` must be trimmed and nonzero
+/// 
+/// For other moduli, the leading coefficient of `rhs` must be a unit.
+pub(crate) fn div_rem_polynomial_mod(lhs: &[u64], rhs: &[u64], m: u64) -> (Vec<u64>, Vec<u64>)
+{
+    let n = rhs.len();
+    let inv = inv_mod(rhs[n - 1], m);
+
+    let mut r = lhs.to_vec();
+    let mut q = vec![0; (r.len() + 1).saturating_sub(n)];
+    for k in (0..q.len()).rev()
+    {
+        let qk = mul_mod(r[k + n - 1], inv, m);
+        q[k] = qk;
+        for (r, &c) in r[k..k + n].iter_mut()
+            .zip(rhs.iter())
+        {
+            *r = (*r + m - mul_mod(qk, c, m)) % m;
+        }
+    }
+    r.truncate(n - 1);
+    (q, trim_polynomial(r))
+}
+
+/// The monic greatest common divisor modulo a prime
+pub(crate) fn gcd_polynomial_mod(lhs: &[u64], rhs: &[u64], m: u64) -> Vec<u64>
+{
+    let mut a = trim_polynomial(lhs.to_vec());
+    let mut b = trim_polynomial(rhs.to_vec());
+    while !b.is_empty()
+    {
+        let (_, r) = div_rem_polynomial_mod(&a, &b, m);
+        a = b;
+        b = r;
+    }
+    monic_polynomial_mod(a, m)
+}
+
+/// Raises a polynomial to a power modulo another polynomial and a prime, where `modulus` must be trimmed and nonzero
+pub(crate) fn pow_polynomial_mod(base: &[u64], mut e: u64, modulus: &[u64], m: u64) -> Vec<u64>
+{
+    let rem = |p: Vec<u64>| div_rem_polynomial_mod(&p, modulus, m).1;
+    let mut a = rem(base.to_vec());
+    let mut y = rem(vec![1]);
+    while e > 0
+    {
+        if e % 2 == 1
+        {
+            y = rem(mul_polynomial_mod(&y, &a, m));
+        }
+        a = rem(mul_polynomial_mod(&a, &a, m));
+        e /= 2;
+    }
+    y
+}
+
+/// The extended euclidean algorithm modulo a prime, yielding the monic greatest common divisor `g` along with `s` and `t` such that `s*lhs + t*rhs = g`
+pub(crate) fn ext_gcd_polynomial_mod(lhs: &[u64], rhs: &[u64], m: u64) -> (Vec<u64>, Vec<u64>, Vec<u64>)
+{
+    let (mut r0, mut r1) = (trim_polynomial(lhs.to_vec()), trim_polynomial(rhs.to_vec()));
+    let (mut s0, mut s1) = (vec![1], vec![]);
+    let (mut t0, mut t1) = (vec![], vec![1]);
+    while !r1.is_empty()
+    {
+        let (q, r) = div_rem_polynomial_mod(&r0, &r1, m);
+        let s = sub_polynomial_mod(&s0, &mul_polynomial_mod(&q, &s1, m), m);
+        let t = sub_polynomial_mod(&t0, &mul_polynomial_mod(&q, &t1, m), m);
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1, s);
+        (t0, t1) = (t1, t);
+    }
+    let inv = r0.last()
+        .map(|&lead| inv_mod(lead, m))
+        .unwrap_or(1);
+    let scale = |p: Vec<u64>| trim_polynomial(p.into_iter()
+        .map(|c| mul_mod(c, inv, m))
+        .collect()
+    );
+    (scale(r0), scale(s0), scale(t0))
+}
+
+/// Divides two integer polynomials, yielding [None](None) if the division is not exact, or if it overflows
+pub(crate) fn exact_div_polynomial(lhs: &[i128], rhs: &[i128]) -> Option<Vec<i128>>
+{
+    let rhs = &rhs[..rhs.iter().rposition(|&c| c != 0)? + 1];
+    let n = rhs.len();
+    let lead = rhs[n - 1];
+
+    let mut r = lhs.to_vec();
+    let mut q = vec![0; (r.len() + 1).saturating_sub(n)];
+    for k in (0..q.len()).rev()
+    {
+        let c = r[k + n - 1];
+        if c % lead != 0
+        {
+            return None
+        }
+        let qk = c/lead;
+        q[k] = qk;
+        for (r, &c) in r[k..k + n].iter_mut()
+            .zip(rhs.iter())
+        {
+            *r = r.checked_sub(qk.checked_mul(c)?)?;
+        }
+    }
+    r.iter()
+        .all(|&c| c == 0)
+        .then_some(trim_polynomial(q))
+}
+
+/// The greatest common divisor of two integer polynomials up to content, using a single large prime
+/// 
+/// The gcd is found modulo a prime, scaled by the gcd of the leading coefficients, and lifted. The prime is large enough if it exceeds
+/// twice Mignotte's bound on the coefficients of any factor, and a result that does not divide both polynomials means the prime was unlucky.
+/// Yields [None](None) if the bound exceeds the primes, or every prime was unlucky.
+pub(crate) fn modular_gcd_polynomial(lhs: &[i128], rhs: &[i128]) -> Option<Vec<i128>>
+{
+    let a = primitive_polynomial(trim_polynomial(lhs.to_vec()));
+    let b = primitive_polynomial(trim_polynomial(rhs.to_vec()));
+    if a.is_empty() || b.is_empty()
+    {
+        return Some(if a.is_empty() {b} else {a})
+    }
+
+    let gamma = gcd_coefficient(a[a.len() - 1], b[b.len() - 1]);
+    let degree = a.len().min(b.len()) - 1;
+    let norm = a.iter()
+        .chain(b.iter())
+        .map(|&c| c.unsigned_abs() as f64)
+        .fold(0.0, f64::max);
+    let bound = 2f64.powi(degree as i32)*((degree + 1) as f64).sqrt()*norm*gamma as f64;
+
+    for m in MODULAR_PRIMES
+    {
+        if 2.0*bound >= m as f64
+        {
+            return None
+        }
+
+        let g = gcd_polynomial_mod(&reduce_polynomial_mod(&a, m), &reduce_polynomial_mod(&b, m), m);
+        if g.len() <= 1
+        {
+            return Some(vec![1])
+        }
+
+        let gamma = gamma.rem_euclid(m as i128) as u64;
+        let g = primitive_polynomial(lift_polynomial_mod(&g.into_iter()
+            .map(|c| mul_mod(c, gamma, m))
+            .collect::<Vec<_>>(), m));
+        if exact_div_polynomial(&a, &g).is_some() && exact_div_polynomial(&b, &g).is_some()
+        {
+            return Some(g)
+        }
+    }
+    None
+}
+
+/// Divides out the content of a big integer polynomial and makes the leading coefficient positive
+pub(crate) fn primitive_big_polynomial(polynomial: Vec<BigInt>) -> Vec<BigInt>
+{
+    let mut content = polynomial.iter()
+        .fold(BigInt::zero(), |mut a, b| {
+            let mut b = b.clone();
+            while !b.is_zero()
+            {
+                let r = &a % &b;
+                a = b;
+                b = r;
+            }
+            a.abs()
+        });
+    if content.is_zero()
+    {
+        return polynomial
+    }
+    if polynomial.last().is_some_and(Signed::is_negative)
+    {
+        content = -content
+    }
+    polynomial.into_iter()
+        .map(|c| c/&content)
+        .collect()
+}
+
+/// Divides two big integer polynomials, yielding [None](None) if the division is not exact
+pub(crate) fn exact_div_big_polynomial(lhs: &[BigInt], rhs: &[BigInt]) -> Option<Vec<BigInt>>
+{
+    let rhs = &rhs[..rhs.iter().rposition(|c| !c.is_zero())? + 1];
+    let n = rhs.len();
+    let lead = &rhs[n - 1];
+
+    let mut r = lhs.to_vec();
+    let mut q = vec![BigInt::zero(); (r.len() + 1).saturating_sub(n)];
+    for k in (0..q.len()).rev()
+    {
+        let c = &r[k + n - 1];
+        if !(c % lead).is_zero()
+        {
+            return None
+        }
+        let qk = c/lead;
+        for (r, b) in r[k..k + n].iter_mut()
+            .zip(rhs.iter())
+        {
+            *r -= &qk*b;
+        }
+        q[k] = qk;
+    }
+    r.iter()
+        .all(Zero::is_zero)
+        .then_some(trim_polynomial(q))
+}
+
+/// Reduces every coefficient of a big integer polynomial to its least nonnegative residue, and trims trailing zeros
+pub(crate) fn reduce_big_polynomial_mod(polynomial: &[BigInt], m: &BigInt) -> Vec<BigInt>
+{
+    trim_polynomial(polynomial.iter()
+        .map(|c| {
+            let r = c % m;
+            if r.is_negative() {r + m} else {r}
+        }).collect()
+    )
+}
+
+/// Lifts every residue of a big integer polynomial to the symmetric range `(-m/2, m/2]`
+pub(crate) fn lift_big_polynomial_mod(polynomial: &[BigInt], m: &BigInt) -> Vec<BigInt>
+{
+    let half = m/2;
+    polynomial.iter()
+        .map(|c| if c > &half {c - m} else {c.clone()})
+        .collect()
+}
+
+pub(crate) fn mul_big_polynomial_mod(lhs: &[BigInt], rhs: &[BigInt], m: &BigInt) -> Vec<BigInt>
+{
+    if lhs.is_empty() || rhs.is_empty()
+    {
+        return vec![]
+    }
+    let mut y = vec![BigInt::zero(); lhs.len() + rhs.len() - 1];
+    for (i, a) in lhs.iter()
+        .enumerate()
+    {
+        for (y, b) in y[i..].iter_mut()
+            .zip(rhs.iter())
+        {
+            *y += a*b;
+        }
+    }
+    reduce_big_polynomial_mod(&y, m)
+}
+
+/// Computes `lead(rhs)^(deg(lhs) - deg(rhs) + 1)*lhs mod rhs`, where `rhs` is trimmed and nonzero
+pub(crate) fn pseudo_rem_big_polynomial(lhs: &[BigInt], rhs: &[BigInt]) -> Vec<BigInt>
+{
+    let n = rhs.len();
+    let lead = &rhs[n - 1];
+    let mut r = lhs.to_vec();
+    for k in (0..(r.len() + 1).saturating_sub(n)).rev()
+    {
+        let c = r[k + n - 1].clone();
+        for r in r.iter_mut()
+        {
+            *r *= lead;
+        }
+        for (r, b) in r[k..k + n].iter_mut()
+            .zip(rhs.iter())
+        {
+            *r -= &c*b;
+        }
+    }
+    trim_polynomial(r)
+}
+
+/// The greatest common divisor of two big integer polynomials up to content, using the primitive polynomial remainder sequence
+pub(crate) fn gcd_big_polynomial(lhs: &[BigInt], rhs: &[BigInt]) -> Vec<BigInt>
+{
+    let mut a = primitive_big_polynomial(trim_polynomial(lhs.to_vec()));
+    let mut b = primitive_big_polynomial(trim_polynomial(rhs.to_vec()));
+    if a.len() < b.len()
+    {
+        core::mem::swap(&mut a, &mut b);
+    }
+    while !b.is_empty()
+    {
+        let r = primitive_big_polynomial(pseudo_rem_big_polynomial(&a, &b));
+        a = b;
+        b = r;
+    }
+    a
+}
@@ -7,6 +7,11 @@ pub trait MulPolynomial<Rhs>
 
     /// Multiplies two polynomials, and yields a new, larger polynomial
     /// 
+    /// Arrays are multiplied by direct convolution. Slices and vectors of coefficients forming a ring, such as integers, rationals and floating point numbers,
+    /// use Karatsuba's algorithm when both polynomials have at least [KARATSUBA_MULTIPLICATION_THRESHOLD](crate::KARATSUBA_MULTIPLICATION_THRESHOLD) coefficients.
+    /// Floating point numbers, complex numbers and [integers modulo a prime](crate::ModInt) use a fast transform for even longer polynomials,
+    /// as given by [FftCoefficient::THRESHOLD](crate::FftCoefficient::THRESHOLD), where floating point products are subject to rounding errors relative to the largest coefficients.
+    /// 
     /// # Example
     /// 
     /// ```rust
@@ -64,6 +69,50 @@ where
     }
 }
 
+/// Multiplies polynomials in slices and vectors, using the fastest algorithm available for the coefficients
+/// 
+/// Any coefficients can be multiplied by direct convolution. This is specialized for coefficients forming a ring,
+/// to use [Karatsuba's algorithm](crate::KARATSUBA_MULTIPLICATION_THRESHOLD) for long polynomials, and further for [FFT coefficients](crate::FftCoefficient),
+/// to use a fast transform for even longer polynomials.
+#[cfg(feature = "std")]
+pub(crate) trait MulCoefficients<Rhs>: Mul<Rhs> + Sized
+{
+    fn mul_coefficients(lhs: &[Self], rhs: &[Rhs]) -> Vec<<Self as Mul<Rhs>>::Output>;
+}
+
+#[cfg(feature = "std")]
+impl<C1, C2> MulCoefficients<C2> for C1
+where
+    C1: Mul<C2> + Copy,
+    C2: Copy,
+    <C1 as Mul<C2>>::Output: Add<<C1 as Mul<C2>>::Output, Output = <C1 as Mul<C2>>::Output> + Default
+{
+    default fn mul_coefficients(lhs: &[C1], rhs: &[C2]) -> Vec<<C1 as Mul<C2>>::Output>
+    {
+        convolve_polynomial(lhs, rhs)
+    }
+}
+
+/// Direct convolution, which takes `O(nm)` operations for polynomials of lengths `n` and `m`
+#[cfg(feature = "std")]
+pub(crate) fn convolve_polynomial<C1, C2>(lhs: &[C1], rhs: &[C2]) -> Vec<<C1 as Mul<C2>>::Output>
+where
+    C1: Mul<C2> + Copy,
+    C2: Copy,
+    <C1 as Mul<C2>>::Output: Add<<C1 as Mul<C2>>::Output, Output = <C1 as Mul<C2>>::Output> + Default
+{
+    let lhs_len = lhs.len();
+    let rhs_len = rhs.len();
+    let len = lhs_len + rhs_len - 1;
+    (1..=len)
+        .map(|k| (k.saturating_sub(rhs_len)..k.min(lhs_len))
+            .zip((k.saturating_sub(lhs_len)..k.min(rhs_len)).rev())
+            .map(|(i, j)| lhs[i]*rhs[j])
+            .reduce(|a, b| a + b)
+            .unwrap_or_default()
+        ).collect()
+}
+
 #[cfg(feature = "std")]
 impl<C1, C2> MulPolynomial<&[C2]> for &[C1]
 where
@@ -75,16 +124,7 @@ where
 
     fn mul_polynomial(self, rhs: &[C2]) -> Self::Output
     {
-        let self_len = self.len();
-        let rhs_len = rhs.len();
-        let len = self_len + rhs_len - 1;
-        (1..=len)
-            .map(|k| (k.saturating_sub(rhs_len)..k.min(self_len))
-                .zip((k.saturating_sub(self_len)..k.min(rhs_len)).rev())
-                .map(|(i, j)| self[i]*rhs[j])
-                .reduce(|a, b| a + b)
-                .unwrap_or_default()
-            ).collect()
+        C1::mul_coefficients(self, rhs)
     }
 }
 
@@ -0,0 +1,236 @@
+use core::{ops::{Add, Mul, Sub}, alloc::Allocator};
+
+use num_traits::{One, Zero};
+
+use super::*;
+
+/// The number of coefficients and points from which [evaluate_many](MultipointPolynomial::evaluate_many) switches from Horner's method to a subproduct tree,
+/// for [ExactArithmetic](ExactArithmetic) coefficients
+/// 
+/// With the benchmarks in `benches/multipoint_polynomial.rs`, the subproduct tree breaks even with Horner's method at about 256 coefficients and points
+/// of [ModInt<NTT_PRIME>](ModInt), where [mul_polynomial](MulPolynomial::mul_polynomial) uses the number-theoretic transform, and is four to six times as fast at 4096.
+pub const MULTIPOINT_EVALUATION_THRESHOLD: usize = 256;
+
+/// The number of points at the leaves of the subproduct tree, where the remainders are evaluated with Horner's method instead of being reduced any further
+/// 
+/// Reducing down to single points spends most of the time inverting tiny power series. Leaves of 64 points were the fastest in the benchmarks,
+/// ahead of 16, 32 and 128.
+const SUBPRODUCT_TREE_LEAF_LENGTH: usize = 64;
+
+pub trait MultipointPolynomial<X>: Polynomial<X>
+{
+    /// Evaluates a polynomial at many points
+    /// 
+    /// Small polynomials, or few points, are evaluated one by one using Horner's method, which takes `O(nm)` operations for `n` coefficients and `m` points.
+    /// From [MULTIPOINT_EVALUATION_THRESHOLD](MULTIPOINT_EVALUATION_THRESHOLD), the points are split into batches of about `n` points. For each batch,
+    /// a subproduct tree of the polynomials `x - xi` is built, and the polynomial is reduced modulo each node from the root down to the leaves,
+    /// where the remainders are small enough to be evaluated at the points of each leaf with Horner's method. The products use [mul_polynomial](MulPolynomial::mul_polynomial), and the remainders
+    /// multiply by the inverse of the reversed divisor as a power series, found by Newton's iteration, so that the batch takes `O(M(n)log(n))` operations,
+    /// where `M(n)` is the cost of a product.
+    /// 
+    /// The subproduct tree is only used for coefficients marked with [ExactArithmetic](ExactArithmetic), such as [integers modulo a prime](ModInt).
+    /// Everything else always uses Horner's method, since the intermediate products of the tree overflow primitive integers, even when the values do not,
+    /// and the tree is numerically unstable for real and complex floating point numbers.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// let p: Vec<i128> = (0..100).map(|i| i % 3 - 1).collect();
+    /// let x: Vec<i128> = (0..200).map(|i| i % 3 - 1).collect();
+    /// 
+    /// assert_eq!(
+    ///     p.evaluate_many(&x),
+    ///     x.iter().map(|&x| p.as_slice().evaluate_as_polynomial(x)).collect::<Vec<_>>()
+    /// );
+    /// ```
+    fn evaluate_many(&self, x: &[X]) -> Vec<Self::Y>;
+}
+
+/// Coefficients whose arithmetic is exact and cannot overflow, for which [evaluate_many](MultipointPolynomial::evaluate_many) may use a subproduct tree
+/// 
+/// Implement this for other exact coefficient types, such as the elements of other finite fields, to use the subproduct tree for them as well.
+pub trait ExactArithmetic {}
+
+impl<const P: u64> ExactArithmetic for ModInt<P> {}
+
+/// Evaluates a polynomial at many points using Horner's method, which is specialized to a subproduct tree for [ExactArithmetic](ExactArithmetic) coefficients
+trait EvaluateMany: Sized
+{
+    fn evaluate_many(polynomial: &[Self], x: &[Self]) -> Vec<Self>;
+}
+
+impl<T> EvaluateMany for T
+where
+    T: Zero + Add<Output = T> + Mul<Output = T> + Default + Copy
+{
+    default fn evaluate_many(polynomial: &[T], x: &[T]) -> Vec<T>
+    {
+        evaluate_many_horner(polynomial, x)
+    }
+}
+
+impl<T> EvaluateMany for T
+where
+    T: ExactArithmetic + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Default + Copy
+{
+    fn evaluate_many(polynomial: &[T], x: &[T]) -> Vec<T>
+    {
+        if polynomial.len() < MULTIPOINT_EVALUATION_THRESHOLD || x.len() < MULTIPOINT_EVALUATION_THRESHOLD
+        {
+            return evaluate_many_horner(polynomial, x)
+        }
+
+        x.chunks(polynomial.len())
+            .flat_map(|x| {
+                let tree = subproduct_tree(x);
+                let remainders = tree.iter()
+                    .rev()
+                    .fold(vec![polynomial.to_vec()], |r, level| level.iter()
+                        .enumerate()
+                        .map(|(i, m)| rem_monic_polynomial(&r[i/2], m))
+                        .collect()
+                    );
+                x.chunks(SUBPRODUCT_TREE_LEAF_LENGTH)
+                    .zip(remainders)
+                    .flat_map(|(x, r)| evaluate_many_horner(&r, x))
+                    .collect::<Vec<_>>()
+            }).collect()
+    }
+}
+
+fn evaluate_many_horner<T>(polynomial: &[T], x: &[T]) -> Vec<T>
+where
+    T: Zero + Add<Output = T> + Mul<Output = T> + Default + Copy
+{
+    x.iter()
+        .map(|&x| polynomial.evaluate_as_polynomial(x))
+        .collect()
+}
+
+/// The levels of the subproduct tree, from the leaves up to the product of every `x - xi`, where every node is monic
+/// 
+/// Each leaf is the product for [SUBPRODUCT_TREE_LEAF_LENGTH](SUBPRODUCT_TREE_LEAF_LENGTH) points, multiplied one factor at a time.
+fn subproduct_tree<T>(x: &[T]) -> Vec<Vec<Vec<T>>>
+where
+    T: Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Default + Copy
+{
+    let mut tree = vec![x.chunks(SUBPRODUCT_TREE_LEAF_LENGTH)
+        .map(|x| x.iter()
+            .fold(vec![T::one()], |mut p, &x| {
+                p.insert(0, T::zero());
+                for i in 0..p.len() - 1
+                {
+                    p[i] = p[i] - x*p[i + 1];
+                }
+                p
+            })
+        ).collect::<Vec<_>>()
+    ];
+    while let Some(level) = tree.last().filter(|level| level.len() > 1)
+    {
+        let level = level.chunks(2)
+            .map(|pair| match pair
+            {
+                [a, b] => a.as_slice().mul_polynomial(b.as_slice()),
+                _ => pair[0].clone()
+            }).collect();
+        tree.push(level)
+    }
+    tree
+}
+
+/// The product of two polynomials, truncated to its first `n` coefficients
+fn mul_truncated_polynomial<T>(lhs: &[T], rhs: &[T], n: usize) -> Vec<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Default + Copy
+{
+    let mut y = lhs[..lhs.len().min(n)].mul_polynomial(&rhs[..rhs.len().min(n)]);
+    y.truncate(n);
+    y
+}
+
+/// The inverse of a power series with a constant term of one, modulo `x^n`, by Newton's iteration `g = g(2 - hg)`, which doubles the number of correct terms
+fn inv_series<T>(h: &[T], n: usize) -> Vec<T>
+where
+    T: Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Default + Copy
+{
+    let mut g = vec![T::one()];
+    while g.len() < n
+    {
+        let k = (2*g.len()).min(n);
+        let mut e = mul_truncated_polynomial(h, &g, k);
+        e[0] = e[0] - T::one();
+        let ge = mul_truncated_polynomial(&g, &e, k);
+        g.resize(k, T::zero());
+        for (g, ge) in g.iter_mut()
+            .zip(ge)
+        {
+            *g = *g - ge;
+        }
+    }
+    g.truncate(n);
+    g
+}
+
+/// The remainder of a polynomial modulo a monic polynomial
+/// 
+/// The quotient is the reversal of the reversed polynomial times the inverse of the reversed divisor as a power series, which has a constant term of one since the divisor is monic.
+fn rem_monic_polynomial<T>(polynomial: &[T], m: &[T]) -> Vec<T>
+where
+    T: Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Default + Copy
+{
+    let d = m.len() - 1;
+    if polynomial.len() <= d
+    {
+        return polynomial.to_vec()
+    }
+
+    let n = polynomial.len() - d;
+    let reverse = |p: &[T]| p.iter()
+        .rev()
+        .copied()
+        .collect::<Vec<_>>();
+    let inv = inv_series(&reverse(m), n);
+    let mut q = mul_truncated_polynomial(&reverse(polynomial), &inv, n);
+    q.reverse();
+
+    let qm = mul_truncated_polynomial(&q, m, d);
+    polynomial[..d].iter()
+        .zip(qm.into_iter()
+            .chain(core::iter::repeat(T::zero()))
+        ).map(|(&p, qm)| p - qm)
+        .collect()
+}
+
+impl<T> MultipointPolynomial<T> for &[T]
+where
+    T: Zero + Add<Output = T> + Mul<Output = T> + Default + Copy
+{
+    fn evaluate_many(&self, x: &[T]) -> Vec<T>
+    {
+        T::evaluate_many(self, x)
+    }
+}
+
+impl<T, A> MultipointPolynomial<T> for Vec<T, A>
+where
+    A: Allocator,
+    T: Zero + Add<Output = T> + Mul<Output = T> + Default + Copy
+{
+    fn evaluate_many(&self, x: &[T]) -> Vec<T>
+    {
+        T::evaluate_many(self.as_slice(), x)
+    }
+}
+
+impl<T, const N: usize> MultipointPolynomial<T> for [T; N]
+where
+    T: Zero + Add<Output = T> + Mul<Output = T> + Default + Copy
+{
+    fn evaluate_many(&self, x: &[T]) -> Vec<T>
+    {
+        T::evaluate_many(self.as_slice(), x)
+    }
+}
@@ -0,0 +1,64 @@
+use core::{alloc::Allocator, ops::Neg};
+
+#[const_trait]
+pub trait NegPolynomial
+{
+    type Output;
+
+    /// Negates every coefficient of a polynomial
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// assert_eq!(
+    ///     [1.0, -2.0, 3.0].neg_polynomial(),
+    ///     [-1.0, 2.0, -3.0]
+    /// );
+    /// ```
+    fn neg_polynomial(self) -> Self::Output;
+}
+
+impl<C, const N: usize> /*const*/ NegPolynomial for [C; N]
+where
+    C: /*~const*/ Neg
+{
+    type Output = [<C as Neg>::Output; N];
+
+    fn neg_polynomial(self) -> Self::Output
+    {
+        self.map(Neg::neg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> NegPolynomial for &[C]
+where
+    C: Neg + Copy
+{
+    type Output = Vec<<C as Neg>::Output>;
+
+    fn neg_polynomial(self) -> Self::Output
+    {
+        self.iter()
+            .map(|&c| -c)
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, A> NegPolynomial for Vec<C, A>
+where
+    A: Allocator,
+    C: Neg
+{
+    type Output = Vec<<C as Neg>::Output>;
+
+    fn neg_polynomial(self) -> Self::Output
+    {
+        self.into_iter()
+            .map(Neg::neg)
+            .collect()
+    }
+}
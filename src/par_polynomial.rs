@@ -0,0 +1,139 @@
+use core::{alloc::Allocator, ops::{Add, Mul}};
+
+use rayon::prelude::*;
+
+use super::*;
+
+pub trait ParPolynomial<X>
+{
+    type Y;
+
+    /// Evaluates a polynomial at many points in parallel, using Horner's method for each point
+    /// 
+    /// The points are split between the threads of the global [rayon](rayon) thread pool, and the values are yielded in the order of the points.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// let p = [1, 2, 3];
+    /// let x: Vec<i64> = (0..10000).collect();
+    /// 
+    /// assert_eq!(
+    ///     p.par_evaluate_many(&x),
+    ///     x.iter().map(|&x| 1 + x*2 + x*x*3).collect::<Vec<_>>()
+    /// );
+    /// ```
+    fn par_evaluate_many(&self, x: &[X]) -> Vec<Self::Y>;
+}
+
+pub trait ParPolynomialNd<X, Y, const N: usize>
+{
+    /// Evaluates a multivariable polynomial at many points in parallel
+    /// 
+    /// The points are split between the threads of the global [rayon](rayon) thread pool, and the values are yielded in the order of the points.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// let p: [[u64; 2]; 2] = [
+    ///     [1, 2],
+    ///     [3, 4]
+    /// ];
+    /// let xy = [[0u64, 0], [1, 0], [0, 1], [2, 3]];
+    /// 
+    /// assert_eq!(
+    ///     p.par_evaluate_many_nd(&xy),
+    ///     xy.map(|[x, y]| 1 + y*2 + x*(3 + y*4)).to_vec()
+    /// );
+    /// ```
+    fn par_evaluate_many_nd(&self, x: &[[X; N]]) -> Vec<Y>;
+
+    /// Evaluates a multivariable polynomial in parallel on the grid spanned by the given points along each axis
+    /// 
+    /// The values are yielded flattened in row-major order, where the last axis varies the fastest,
+    /// which is the same order as evaluating a nested array of grid points with `map_nd` and flattening it.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// let p: [[u64; 2]; 2] = [
+    ///     [1, 2],
+    ///     [3, 4]
+    /// ];
+    /// 
+    /// assert_eq!(
+    ///     p.par_evaluate_grid_nd([&[0u64, 1][..], &[0, 1, 2][..]]),
+    ///     vec![1, 3, 5, 4, 10, 16]
+    /// );
+    /// ```
+    fn par_evaluate_grid_nd(&self, axes: [&[X]; N]) -> Vec<Y>;
+}
+
+macro_rules! impl_par_polynomial {
+    ($(<{$($generics:tt)+}>)? for $type:ty $(where $($where:tt)+)?) => {
+        impl<C, X, $($($generics)+)?> ParPolynomial<X> for $type
+        where
+            C: Into<<X as Mul<C>>::Output> + Copy + Sync,
+            X: Mul<C> + Copy + Sync,
+            <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output> + Send
+            $(,$($where)+)?
+        {
+            type Y = <X as Mul<C>>::Output;
+
+            fn par_evaluate_many(&self, x: &[X]) -> Vec<Self::Y>
+            {
+                let p = &self[..];
+                x.par_iter()
+                    .map(|&x| p.evaluate_as_polynomial(x))
+                    .collect()
+            }
+        }
+    };
+}
+
+impl_par_polynomial!(for &[C]);
+impl_par_polynomial!(<{A}> for Vec<C, A> where A: Allocator);
+impl_par_polynomial!(<{const N: usize}> for [C; N]);
+
+impl<P, X, Y, const N: usize> ParPolynomialNd<X, Y, N> for P
+where
+    P: PolynomialNd<X, Y, N> + Copy + Sync,
+    X: Copy + Sync,
+    Y: Send
+{
+    fn par_evaluate_many_nd(&self, x: &[[X; N]]) -> Vec<Y>
+    {
+        x.par_iter()
+            .map(|&x| self.evaluate_as_polynomial_nd(x))
+            .collect()
+    }
+
+    fn par_evaluate_grid_nd(&self, axes: [&[X]; N]) -> Vec<Y>
+    {
+        let len = axes.iter()
+            .map(|axis| axis.len())
+            .product();
+        (0..len).into_par_iter()
+            .map(|mut i| {
+                let mut x = [None; N];
+                for (x, axis) in x.iter_mut()
+                    .zip(axes.iter())
+                    .rev()
+                {
+                    *x = Some(axis[i % axis.len()]);
+                    i /= axis.len();
+                }
+                self.evaluate_as_polynomial_nd(x.map(|x| x.unwrap_or_else(|| unreachable!())))
+            }).collect()
+    }
+}
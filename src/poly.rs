@@ -0,0 +1,223 @@
+use core::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Neg, Rem, Sub};
+
+use num_traits::Zero;
+
+use super::*;
+
+/// A polynomial with a fixed number of coefficients, stored in ascending order, so that the last coefficient is the leading coefficient
+/// 
+/// Unlike a bare array, the arithmetic operators act on it as a polynomial, by delegating to [AddPolynomial](AddPolynomial), [SubPolynomial](SubPolynomial),
+/// [MulPolynomial](MulPolynomial), [NegPolynomial](NegPolynomial) and [DivRemPolynomial](DivRemPolynomial), and it can be called like a function
+/// to evaluate it with [evaluate_as_polynomial](Polynomial::evaluate_as_polynomial). The length of a result is found from the lengths of the operands at compile time.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use polynomial_ops::*;
+/// 
+/// let p = Poly([1.0, 1.0]);
+/// let q = Poly([-1.0, 1.0]);
+/// 
+/// assert_eq!(p*q, Poly([-1.0, 0.0, 1.0]));
+/// assert_eq!(p + q, Poly([0.0, 2.0]));
+/// assert_eq!(p - q, Poly([2.0, 0.0]));
+/// assert_eq!(-p, Poly([-1.0, -1.0]));
+/// 
+/// assert_eq!((p*q)(3.0), 8.0);
+/// assert_eq!((p*q)[2], 1.0);
+/// 
+/// // 3x^2 + 2x + 1 = (3x - 1)(x + 1) + 2
+/// let p = Poly([1.0, 2.0, 3.0]);
+/// let q = Poly([1.0, 1.0]);
+/// 
+/// assert_eq!(p/q, Poly([-1.0, 3.0]));
+/// assert_eq!(p%q, Poly([2.0]));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Poly<C, const N: usize>(pub [C; N]);
+
+impl<C, const N: usize> Poly<C, N>
+{
+    pub const fn new(coefficients: [C; N]) -> Self
+    {
+        Self(coefficients)
+    }
+
+    pub fn into_inner(self) -> [C; N]
+    {
+        self.0
+    }
+}
+
+impl<C, const N: usize> From<[C; N]> for Poly<C, N>
+{
+    fn from(coefficients: [C; N]) -> Self
+    {
+        Self(coefficients)
+    }
+}
+
+impl<C1, C2, const N1: usize, const N2: usize> Add<Poly<C2, N2>> for Poly<C1, N1>
+where
+    C1: Add<C2> + Default + Copy,
+    C2: Default + Copy,
+    <C1 as Add<C2>>::Output: Default + Copy,
+    [(); polynomial_sum_length(N1, N2)]:
+{
+    type Output = Poly<<C1 as Add<C2>>::Output, {polynomial_sum_length(N1, N2)}>;
+
+    fn add(self, rhs: Poly<C2, N2>) -> Self::Output
+    {
+        Poly(self.0.add_polynomial(rhs.0))
+    }
+}
+
+impl<C1, C2, const N1: usize, const N2: usize> Sub<Poly<C2, N2>> for Poly<C1, N1>
+where
+    C1: Sub<C2> + Default + Copy,
+    C2: Default + Copy,
+    <C1 as Sub<C2>>::Output: Default + Copy,
+    [(); polynomial_sum_length(N1, N2)]:
+{
+    type Output = Poly<<C1 as Sub<C2>>::Output, {polynomial_sum_length(N1, N2)}>;
+
+    fn sub(self, rhs: Poly<C2, N2>) -> Self::Output
+    {
+        Poly(self.0.sub_polynomial(rhs.0))
+    }
+}
+
+impl<C1, C2, const N1: usize, const N2: usize> Mul<Poly<C2, N2>> for Poly<C1, N1>
+where
+    C1: Mul<C2> + Copy,
+    C2: Copy,
+    <C1 as Mul<C2>>::Output: AddAssign<<C1 as Mul<C2>>::Output> + Default + Copy,
+    [(); N1 + N2 - 1]:
+{
+    type Output = Poly<<C1 as Mul<C2>>::Output, {N1 + N2 - 1}>;
+
+    fn mul(self, rhs: Poly<C2, N2>) -> Self::Output
+    {
+        Poly(self.0.mul_polynomial(rhs.0))
+    }
+}
+
+impl<C, const N: usize> Neg for Poly<C, N>
+where
+    C: Neg
+{
+    type Output = Poly<<C as Neg>::Output, N>;
+
+    fn neg(self) -> Self::Output
+    {
+        Poly(self.0.neg_polynomial())
+    }
+}
+
+impl<C, const N1: usize, const N2: usize> Div<Poly<C, N2>> for Poly<C, N1>
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy,
+    [(); N1 - N2 + 1]:,
+    [(); N2 - 1]:
+{
+    type Output = Poly<C, {N1 - N2 + 1}>;
+
+    /// The quotient of polynomial long division
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the divisor's leading coefficient is zero.
+    fn div(self, rhs: Poly<C, N2>) -> Self::Output
+    {
+        match self.0.div_rem_polynomial(rhs.0)
+        {
+            Ok((q, _)) => Poly(q),
+            Err(error) => panic!("{}", error)
+        }
+    }
+}
+
+impl<C, const N1: usize, const N2: usize> Rem<Poly<C, N2>> for Poly<C, N1>
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy,
+    [(); N1 - N2 + 1]:,
+    [(); N2 - 1]:
+{
+    type Output = Poly<C, {N2 - 1}>;
+
+    /// The remainder of polynomial long division
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the divisor's leading coefficient is zero.
+    fn rem(self, rhs: Poly<C, N2>) -> Self::Output
+    {
+        match self.0.div_rem_polynomial(rhs.0)
+        {
+            Ok((_, r)) => Poly(r),
+            Err(error) => panic!("{}", error)
+        }
+    }
+}
+
+impl<C, I, const N: usize> Index<I> for Poly<C, N>
+where
+    [C; N]: Index<I>
+{
+    type Output = <[C; N] as Index<I>>::Output;
+
+    fn index(&self, index: I) -> &Self::Output
+    {
+        &self.0[index]
+    }
+}
+
+impl<C, I, const N: usize> IndexMut<I> for Poly<C, N>
+where
+    [C; N]: IndexMut<I>
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output
+    {
+        &mut self.0[index]
+    }
+}
+
+impl<C, X, const N: usize> FnOnce<(X,)> for Poly<C, N>
+where
+    C: Into<<X as Mul<C>>::Output> + Copy,
+    X: Mul<C> + Copy,
+    <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
+{
+    type Output = <X as Mul<C>>::Output;
+
+    extern "rust-call" fn call_once(self, (x,): (X,)) -> Self::Output
+    {
+        self.call((x,))
+    }
+}
+
+impl<C, X, const N: usize> FnMut<(X,)> for Poly<C, N>
+where
+    C: Into<<X as Mul<C>>::Output> + Copy,
+    X: Mul<C> + Copy,
+    <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (X,)) -> Self::Output
+    {
+        self.call((x,))
+    }
+}
+
+impl<C, X, const N: usize> Fn<(X,)> for Poly<C, N>
+where
+    C: Into<<X as Mul<C>>::Output> + Copy,
+    X: Mul<C> + Copy,
+    <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
+{
+    extern "rust-call" fn call(&self, (x,): (X,)) -> Self::Output
+    {
+        self.0.as_slice().evaluate_as_polynomial(x)
+    }
+}
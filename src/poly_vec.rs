@@ -0,0 +1,274 @@
+use core::{alloc::Allocator, hash::{Hash, Hasher}, ops::{Add, Div, Index, IndexMut, Mul, Neg, Rem, Sub}};
+use std::alloc::Global;
+
+use num_traits::Zero;
+
+use super::*;
+
+/// A polynomial with its coefficients in a vector, stored in ascending order, so that the last coefficient is the leading coefficient
+/// 
+/// This is the growable counterpart of [Poly](Poly), for polynomials whose degree is only known at runtime.
+/// The arithmetic operators delegate to [AddPolynomial](AddPolynomial), [SubPolynomial](SubPolynomial), [MulPolynomial](MulPolynomial),
+/// [NegPolynomial](NegPolynomial) and [DivRemPolynomial](DivRemPolynomial), and it can be called like a function to evaluate it.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use polynomial_ops::*;
+/// 
+/// let p = PolyVec(vec![1, 1]);
+/// let q = PolyVec(vec![-1, 1]);
+/// 
+/// assert_eq!(p.clone()*q.clone(), PolyVec(vec![-1, 0, 1]));
+/// assert_eq!(p.clone() + q.clone(), PolyVec(vec![0, 2]));
+/// assert_eq!(p.clone() - q.clone(), PolyVec(vec![2, 0]));
+/// assert_eq!(-p.clone(), PolyVec(vec![-1, -1]));
+/// 
+/// assert_eq!((p*q)(3), 8);
+/// 
+/// // 3x^2 + 2x + 1 = (3x - 1)(x + 1) + 2
+/// let p = PolyVec(vec![1.0, 2.0, 3.0]);
+/// let q = PolyVec(vec![1.0, 1.0]);
+/// 
+/// assert_eq!(p.clone()/q.clone(), PolyVec(vec![-1.0, 3.0]));
+/// assert_eq!(p%q, PolyVec(vec![2.0]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolyVec<C, A = Global>(pub Vec<C, A>)
+where
+    A: Allocator;
+
+impl<C, A> PolyVec<C, A>
+where
+    A: Allocator
+{
+    pub const fn new(coefficients: Vec<C, A>) -> Self
+    {
+        Self(coefficients)
+    }
+
+    pub fn into_inner(self) -> Vec<C, A>
+    {
+        self.0
+    }
+}
+
+impl<C1, A1, C2, A2> PartialEq<PolyVec<C2, A2>> for PolyVec<C1, A1>
+where
+    A1: Allocator,
+    A2: Allocator,
+    C1: PartialEq<C2>
+{
+    fn eq(&self, rhs: &PolyVec<C2, A2>) -> bool
+    {
+        self.0 == rhs.0
+    }
+}
+
+impl<C, A> Eq for PolyVec<C, A>
+where
+    A: Allocator,
+    C: Eq
+{
+
+}
+
+impl<C, A> Hash for PolyVec<C, A>
+where
+    A: Allocator,
+    C: Hash
+{
+    fn hash<H: Hasher>(&self, state: &mut H)
+    {
+        self.0.hash(state)
+    }
+}
+
+impl<C> Default for PolyVec<C>
+{
+    /// The zero polynomial, with no coefficients
+    fn default() -> Self
+    {
+        Self(Vec::new())
+    }
+}
+
+impl<C, A> From<Vec<C, A>> for PolyVec<C, A>
+where
+    A: Allocator
+{
+    fn from(coefficients: Vec<C, A>) -> Self
+    {
+        Self(coefficients)
+    }
+}
+
+impl<C1, A1, C2, A2> Add<PolyVec<C2, A2>> for PolyVec<C1, A1>
+where
+    A1: Allocator,
+    A2: Allocator,
+    C1: Add<C2> + Default + Copy,
+    C2: Default + Copy
+{
+    type Output = PolyVec<<C1 as Add<C2>>::Output>;
+
+    fn add(self, rhs: PolyVec<C2, A2>) -> Self::Output
+    {
+        PolyVec(self.0.add_polynomial(rhs.0))
+    }
+}
+
+impl<C1, A1, C2, A2> Sub<PolyVec<C2, A2>> for PolyVec<C1, A1>
+where
+    A1: Allocator,
+    A2: Allocator,
+    C1: Sub<C2> + Default + Copy,
+    C2: Default + Copy
+{
+    type Output = PolyVec<<C1 as Sub<C2>>::Output>;
+
+    fn sub(self, rhs: PolyVec<C2, A2>) -> Self::Output
+    {
+        PolyVec(self.0.sub_polynomial(rhs.0))
+    }
+}
+
+impl<C1, A1, C2, A2> Mul<PolyVec<C2, A2>> for PolyVec<C1, A1>
+where
+    A1: Allocator,
+    A2: Allocator,
+    C1: Mul<C2> + Copy,
+    C2: Copy,
+    <C1 as Mul<C2>>::Output: Add<<C1 as Mul<C2>>::Output, Output = <C1 as Mul<C2>>::Output> + Default
+{
+    type Output = PolyVec<<C1 as Mul<C2>>::Output>;
+
+    fn mul(self, rhs: PolyVec<C2, A2>) -> Self::Output
+    {
+        PolyVec(self.0.mul_polynomial(rhs.0))
+    }
+}
+
+impl<C, A> Neg for PolyVec<C, A>
+where
+    A: Allocator,
+    C: Neg
+{
+    type Output = PolyVec<<C as Neg>::Output>;
+
+    fn neg(self) -> Self::Output
+    {
+        PolyVec(self.0.neg_polynomial())
+    }
+}
+
+impl<C, A1, A2> Div<PolyVec<C, A2>> for PolyVec<C, A1>
+where
+    A1: Allocator,
+    A2: Allocator,
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Output = PolyVec<C>;
+
+    /// The quotient of polynomial long division
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the divisor is the zero polynomial.
+    fn div(self, rhs: PolyVec<C, A2>) -> Self::Output
+    {
+        match self.0.div_rem_polynomial(rhs.0)
+        {
+            Ok((q, _)) => PolyVec(q),
+            Err(error) => panic!("{}", error)
+        }
+    }
+}
+
+impl<C, A1, A2> Rem<PolyVec<C, A2>> for PolyVec<C, A1>
+where
+    A1: Allocator,
+    A2: Allocator,
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    type Output = PolyVec<C>;
+
+    /// The remainder of polynomial long division
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the divisor is the zero polynomial.
+    fn rem(self, rhs: PolyVec<C, A2>) -> Self::Output
+    {
+        match self.0.div_rem_polynomial(rhs.0)
+        {
+            Ok((_, r)) => PolyVec(r),
+            Err(error) => panic!("{}", error)
+        }
+    }
+}
+
+impl<C, A, I> Index<I> for PolyVec<C, A>
+where
+    A: Allocator,
+    Vec<C, A>: Index<I>
+{
+    type Output = <Vec<C, A> as Index<I>>::Output;
+
+    fn index(&self, index: I) -> &Self::Output
+    {
+        &self.0[index]
+    }
+}
+
+impl<C, A, I> IndexMut<I> for PolyVec<C, A>
+where
+    A: Allocator,
+    Vec<C, A>: IndexMut<I>
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output
+    {
+        &mut self.0[index]
+    }
+}
+
+impl<C, A, X> FnOnce<(X,)> for PolyVec<C, A>
+where
+    A: Allocator,
+    C: Into<<X as Mul<C>>::Output> + Copy,
+    X: Mul<C> + Copy,
+    <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
+{
+    type Output = <X as Mul<C>>::Output;
+
+    extern "rust-call" fn call_once(self, (x,): (X,)) -> Self::Output
+    {
+        self.call((x,))
+    }
+}
+
+impl<C, A, X> FnMut<(X,)> for PolyVec<C, A>
+where
+    A: Allocator,
+    C: Into<<X as Mul<C>>::Output> + Copy,
+    X: Mul<C> + Copy,
+    <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (X,)) -> Self::Output
+    {
+        self.call((x,))
+    }
+}
+
+impl<C, A, X> Fn<(X,)> for PolyVec<C, A>
+where
+    A: Allocator,
+    C: Into<<X as Mul<C>>::Output> + Copy,
+    X: Mul<C> + Copy,
+    <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
+{
+    extern "rust-call" fn call(&self, (x,): (X,)) -> Self::Output
+    {
+        self.0.as_slice().evaluate_as_polynomial(x)
+    }
+}
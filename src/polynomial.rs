@@ -1,4 +1,4 @@
-use core::{ops::{Mul, Add, MulAssign, AddAssign}, marker::Destruct, alloc::Allocator};
+use core::{ops::{Mul, Add, MulAssign}, marker::Destruct, alloc::Allocator};
 
 #[const_trait]
 pub trait Polynomial<X>: Sized
@@ -7,6 +7,12 @@ pub trait Polynomial<X>: Sized
 
     /// Evaluates a polynomial
     /// 
+    /// Coefficients are evaluated using Horner's method. See [PolynomialWithStrategy](PolynomialWithStrategy) for other strategies.
+    /// 
+    /// Horner's method multiplies the partial sums by `x`, so the implementations require `<X as Mul<C>>::Output: Mul<X, Output = <X as Mul<C>>::Output>`
+    /// instead of `X: MulAssign`, which the powers of `x` were accumulated with before. This is a breaking change for argument types whose products
+    /// with the coefficients cannot be multiplied by `x` again, while coefficients and arguments of the same numeric type are unaffected.
+    /// 
     /// # Example
     /// 
     /// ```rust
@@ -27,8 +33,8 @@ pub trait Polynomial<X>: Sized
 impl<C, X> /*const*/ Polynomial<X> for &[C]
 where
     C: /*~const*/ Into<<X as Mul<C>>::Output> + Copy,
-    X: /*~const*/ Mul<C> + /*~const*/ MulAssign + Copy,
-    <X as Mul<C>>::Output: /*~const*/ Default + /*~const*/ Add<Output = <X as Mul<C>>::Output> + /*~const*/ AddAssign + Default
+    X: /*~const*/ Mul<C> + Copy,
+    <X as Mul<C>>::Output: /*~const*/ Default + /*~const*/ Add<Output = <X as Mul<C>>::Output> + /*~const*/ Mul<X, Output = <X as Mul<C>>::Output>
 {
     type Y = <X as Mul<C>>::Output;
 
@@ -46,8 +52,8 @@ impl<C, X, A> Polynomial<X> for Vec<C, A>
 where
     A: Allocator,
     C: Into<<X as Mul<C>>::Output>,
-    X: Mul<C> + MulAssign + Copy,
-    <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Default
+    X: Mul<C> + Copy,
+    <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
 {
     type Y = <X as Mul<C>>::Output;
 
@@ -60,8 +66,8 @@ where
 impl<C, X, const N: usize> const Polynomial<X> for [C; N]
 where
     C: /*~const*/ Destruct + /*~const*/ Into<<X as Mul<C>>::Output>,
-    X: /*~const*/ Mul<C> + /*~const*/ MulAssign + Copy,
-    <X as Mul<C>>::Output: /*~const*/ Default + /*~const*/ Add<Output = <X as Mul<C>>::Output> + /*~const*/ Destruct
+    X: /*~const*/ Mul<C> + Copy,
+    <X as Mul<C>>::Output: /*~const*/ Default + /*~const*/ Add<Output = <X as Mul<C>>::Output> + /*~const*/ Mul<X, Output = <X as Mul<C>>::Output> + /*~const*/ Destruct
 {
     type Y = <X as Mul<C>>::Output;
 
@@ -74,9 +80,99 @@ where
     }
 }
 
+/// Strategies for evaluating a polynomial
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EvaluationStrategy
+{
+    /// Horner's method, `c0 + x*(c1 + x*(c2 + ...))`
+    /// 
+    /// Takes `N - 1` multiplications and `N - 1` additions. The rounding error is bounded by about `2N` units in the last place
+    /// relative to the evaluation of `|p|` at `|x|`, which makes it the most accurate of the strategies here.
+    /// Every step depends on the one before it though, so it cannot take advantage of instruction-level parallelism.
+    #[default]
+    Horner,
+    /// Estrin's scheme, which combines pairs of coefficients as `c0 + x*c1`, then pairs of those with `x^2`, then `x^4`, and so on
+    /// 
+    /// Takes `N - 1` multiplications and additions, plus `log2(N)` squarings to form the powers of `x`. The dependency chain is only `O(log N)` long,
+    /// so independent subexpressions can be evaluated in parallel by pipelined and superscalar hardware, which typically makes it faster for high degrees.
+    /// The error bound is similar to Horner's, but grows with `log2(N)` rather than `N`, at the expense of the powers of `x` being rounded as well.
+    Estrin,
+    /// The naive method, `c0 + c1*x + c2*x^2 + ...`, accumulating the powers of `x` along the way
+    /// 
+    /// Takes `2N - 2` multiplications and `N - 1` additions. The rounding errors of the powers of `x` accumulate into every term,
+    /// so it is the least accurate and the slowest.
+    Naive
+}
+
+#[const_trait]
+pub trait PolynomialWithStrategy<X>: Polynomial<X>
+{
+    /// Evaluates a polynomial using the given strategy
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// let p: [i64; 5] = [1, 2, 3, 4, 5];
+    /// for x in 0..256
+    /// {
+    ///     let y = 1 + x*2 + x*x*3 + x*x*x*4 + x*x*x*x*5;
+    /// 
+    ///     assert_eq!(p.evaluate_as_polynomial_with(x, EvaluationStrategy::Horner), y);
+    ///     assert_eq!(p.evaluate_as_polynomial_with(x, EvaluationStrategy::Estrin), y);
+    ///     assert_eq!(p.evaluate_as_polynomial_with(x, EvaluationStrategy::Naive), y);
+    /// }
+    /// ```
+    fn evaluate_as_polynomial_with(self, x: X, strategy: EvaluationStrategy) -> Self::Y;
+}
+
+impl<C, X> /*const*/ PolynomialWithStrategy<X> for &[C]
+where
+    C: /*~const*/ Into<<X as Mul<C>>::Output> + Copy,
+    X: /*~const*/ Mul<C> + /*~const*/ Mul<Output = X> + /*~const*/ MulAssign + Copy,
+    <X as Mul<C>>::Output: /*~const*/ Default + /*~const*/ Add<Output = <X as Mul<C>>::Output> + /*~const*/ Mul<X, Output = <X as Mul<C>>::Output>
+{
+    fn evaluate_as_polynomial_with(self, x: X, strategy: EvaluationStrategy) -> Self::Y
+    {
+        match strategy
+        {
+            EvaluationStrategy::Horner => slice_polynomial::evaluate_at_rt(self, x),
+            EvaluationStrategy::Estrin => slice_polynomial::evaluate_estrin(self, x),
+            EvaluationStrategy::Naive => array_polynomial::evaluate_naive(self.iter().copied(), x)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, X, A> PolynomialWithStrategy<X> for Vec<C, A>
+where
+    A: Allocator,
+    C: Into<<X as Mul<C>>::Output> + Copy,
+    X: Mul<C> + Mul<Output = X> + MulAssign + Copy,
+    <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
+{
+    fn evaluate_as_polynomial_with(self, x: X, strategy: EvaluationStrategy) -> Self::Y
+    {
+        self.as_slice().evaluate_as_polynomial_with(x, strategy)
+    }
+}
+
+impl<C, X, const N: usize> /*const*/ PolynomialWithStrategy<X> for [C; N]
+where
+    C: /*~const*/ Into<<X as Mul<C>>::Output> + Copy,
+    X: /*~const*/ Mul<C> + /*~const*/ Mul<Output = X> + /*~const*/ MulAssign + Copy,
+    <X as Mul<C>>::Output: /*~const*/ Default + /*~const*/ Add<Output = <X as Mul<C>>::Output> + /*~const*/ Mul<X, Output = <X as Mul<C>>::Output>
+{
+    fn evaluate_as_polynomial_with(self, x: X, strategy: EvaluationStrategy) -> Self::Y
+    {
+        self.as_slice().evaluate_as_polynomial_with(x, strategy)
+    }
+}
+
 mod slice_polynomial
 {
-    use core::ops::{Mul, MulAssign, Add, AddAssign};
+    use core::ops::{Mul, Add};
 
     use super::array_polynomial;
 
@@ -127,11 +223,48 @@ mod slice_polynomial
     pub fn evaluate_at_rt<C, X>(polynomial: &[C], x: X) -> <X as Mul<C>>::Output
     where
         C: Into<<X as Mul<C>>::Output> + Copy,
-        X: Mul<C> + MulAssign + Copy,
-        <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output>
+        X: Mul<C> + Copy,
+        <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
     {
         array_polynomial::evaluate_at_rt(polynomial.into_iter().map(|&c| c), x)
     }
+
+    pub fn evaluate_estrin<C, X>(polynomial: &[C], x: X) -> <X as Mul<C>>::Output
+    where
+        C: Into<<X as Mul<C>>::Output> + Copy,
+        X: Mul<C> + Mul<Output = X> + Copy,
+        <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
+    {
+        // x^(2^k)
+        let mut x_pow = [x; usize::BITS as usize];
+        let mut k = 1;
+        while 1 << k < polynomial.len()
+        {
+            x_pow[k] = x_pow[k - 1]*x_pow[k - 1];
+            k += 1;
+        }
+        evaluate_estrin_with_powers(polynomial, x, &x_pow)
+    }
+
+    fn evaluate_estrin_with_powers<C, X>(polynomial: &[C], x: X, x_pow: &[X]) -> <X as Mul<C>>::Output
+    where
+        C: Into<<X as Mul<C>>::Output> + Copy,
+        X: Mul<C> + Copy,
+        <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
+    {
+        match polynomial.len()
+        {
+            0 => Default::default(),
+            1 => polynomial[0].into(),
+            2 => polynomial[0].into() + x*polynomial[1],
+            len => {
+                // Split at the largest power of two below the length, so that the lower half is a complete tree
+                let k = (usize::BITS - 1 - (len - 1).leading_zeros()) as usize;
+                let (lower, upper) = polynomial.split_at(1 << k);
+                evaluate_estrin_with_powers(lower, x, x_pow) + evaluate_estrin_with_powers(upper, x, x_pow)*x_pow[k]
+            }
+        }
+    }
 }
 
 mod array_polynomial
@@ -186,6 +319,22 @@ mod array_polynomial
     }*/
     
     pub fn evaluate_at_rt<C, A, X>(polynomial: A, x: X) -> <X as Mul<C>>::Output
+    where
+        A: IntoIterator<Item = C>,
+        A::IntoIter: DoubleEndedIterator,
+        C: Into<<X as Mul<C>>::Output>,
+        X: Mul<C> + Copy,
+        <X as Mul<C>>::Output: Default + Add<Output = <X as Mul<C>>::Output> + Mul<X, Output = <X as Mul<C>>::Output>
+    {
+        polynomial
+            .into_iter()
+            .rev()
+            .map(Into::into)
+            .reduce(|y, c| y*x + c)
+            .unwrap_or(Default::default())
+    }
+
+    pub fn evaluate_naive<C, A, X>(polynomial: A, x: X) -> <X as Mul<C>>::Output
     where
         A: IntoIterator<Item = C>,
         C: Into<<X as Mul<C>>::Output>,
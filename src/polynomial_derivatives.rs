@@ -0,0 +1,123 @@
+use core::{ops::{Mul, Add}, alloc::Allocator};
+
+use num_traits::One;
+
+use super::*;
+
+#[const_trait]
+pub trait PolynomialDerivatives<X>: Polynomial<X>
+{
+    /// Evaluates a polynomial and its first `K` derivatives in a single pass of Horner's method, and yields `[p(x), p'(x), ..., p^(K)(x)]`
+    /// 
+    /// Derivatives beyond the degree of the polynomial are zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use polynomial_ops::*;
+    /// 
+    /// // p(x) = 1 + 2x + 3x^2 + 4x^3
+    /// let p = [1, 2, 3, 4];
+    /// for x in -16..16
+    /// {
+    ///     assert_eq!(
+    ///         p.evaluate_with_derivatives::<4>(x),
+    ///         [
+    ///             1 + 2*x + 3*x*x + 4*x*x*x,
+    ///             2 + 6*x + 12*x*x,
+    ///             6 + 24*x,
+    ///             24,
+    ///             0
+    ///         ]
+    ///     );
+    /// }
+    /// ```
+    fn evaluate_with_derivatives<const K: usize>(self, x: X) -> [Self::Y; K + 1]
+    where
+        [(); K + 1]:;
+}
+
+impl<C, X> /*const*/ PolynomialDerivatives<X> for &[C]
+where
+    C: /*~const*/ Into<<X as Mul<C>>::Output> + Copy,
+    X: /*~const*/ Mul<C> + Copy,
+    <X as Mul<C>>::Output: /*~const*/ Default + /*~const*/ One + /*~const*/ Add<Output = <X as Mul<C>>::Output>
+        + /*~const*/ Mul<X, Output = <X as Mul<C>>::Output> + /*~const*/ Mul<Output = <X as Mul<C>>::Output> + Copy
+{
+    fn evaluate_with_derivatives<const K: usize>(self, x: X) -> [Self::Y; K + 1]
+    where
+        [(); K + 1]:
+    {
+        evaluate_with_derivatives(self.iter().copied(), x)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, X, A> PolynomialDerivatives<X> for Vec<C, A>
+where
+    A: Allocator,
+    C: Into<<X as Mul<C>>::Output>,
+    X: Mul<C> + Copy,
+    <X as Mul<C>>::Output: Default + One + Add<Output = <X as Mul<C>>::Output>
+        + Mul<X, Output = <X as Mul<C>>::Output> + Mul<Output = <X as Mul<C>>::Output> + Copy
+{
+    fn evaluate_with_derivatives<const K: usize>(self, x: X) -> [Self::Y; K + 1]
+    where
+        [(); K + 1]:
+    {
+        evaluate_with_derivatives(self, x)
+    }
+}
+
+impl<C, X, const N: usize> /*const*/ PolynomialDerivatives<X> for [C; N]
+where
+    C: /*~const*/ Into<<X as Mul<C>>::Output>,
+    X: /*~const*/ Mul<C> + Copy,
+    <X as Mul<C>>::Output: /*~const*/ Default + /*~const*/ One + /*~const*/ Add<Output = <X as Mul<C>>::Output>
+        + /*~const*/ Mul<X, Output = <X as Mul<C>>::Output> + /*~const*/ Mul<Output = <X as Mul<C>>::Output> + Copy
+{
+    fn evaluate_with_derivatives<const K: usize>(self, x: X) -> [Self::Y; K + 1]
+    where
+        [(); K + 1]:
+    {
+        evaluate_with_derivatives(self, x)
+    }
+}
+
+fn evaluate_with_derivatives<C, A, X, const K: usize>(polynomial: A, x: X) -> [<X as Mul<C>>::Output; K + 1]
+where
+    A: IntoIterator<Item = C>,
+    A::IntoIter: DoubleEndedIterator,
+    C: Into<<X as Mul<C>>::Output>,
+    X: Mul<C> + Copy,
+    <X as Mul<C>>::Output: Default + One + Add<Output = <X as Mul<C>>::Output>
+        + Mul<X, Output = <X as Mul<C>>::Output> + Mul<Output = <X as Mul<C>>::Output> + Copy,
+    [(); K + 1]:
+{
+    // y[k] accumulates p^(k)(x)/k!
+    let mut y = [Default::default(); K + 1];
+    for c in polynomial.into_iter()
+        .rev()
+    {
+        let mut k = K;
+        while k > 0
+        {
+            y[k] = y[k]*x + y[k - 1];
+            k -= 1;
+        }
+        y[0] = y[0]*x + c.into();
+    }
+
+    let mut n = <X as Mul<C>>::Output::one();
+    let mut factorial = <X as Mul<C>>::Output::one();
+    for yk in y.iter_mut()
+        .skip(2)
+    {
+        n = n + One::one();
+        factorial = factorial*n;
+        *yk = *yk*factorial;
+    }
+    y
+}
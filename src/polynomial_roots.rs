@@ -0,0 +1,279 @@
+use num_complex::Complex;
+use num_traits::{Float, FloatConst};
+
+pub trait PolynomialRoots
+{
+    type Roots;
+    type RealRoots;
+
+    /// Finds the roots of a polynomial of degree one through four, using closed-form formulas
+    /// 
+    /// Linear and quadratic polynomials are solved directly, where the quadratic formula is rearranged to avoid cancellation.
+    /// Cubics are solved using Cardano's formula when they have a single real root, and the trigonometric method when all three roots are real.
+    /// Quartics are solved using Ferrari's method, which splits the polynomial into two quadratics using a real root of the resolvent cubic.
+    /// Cubic and quartic roots are then polished with a single step of Newton's method.
+    /// If the leading coefficient is smaller than the constant term, cubics and quartics are solved through the reversed polynomial, whose roots are the reciprocals,
+    /// so that a leading coefficient near zero does not swamp the smaller roots.
+    /// 
+    /// If the leading coefficient is zero, the polynomial has a lower degree, and the missing roots are given as positive infinity.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use polynomial_ops::*;
+    /// 
+    /// // x^2 + 1
+    /// assert_eq!(
+    ///     [1.0, 0.0, 1.0].roots(),
+    ///     [Complex::new(0.0, -1.0), Complex::new(0.0, 1.0)]
+    /// );
+    /// 
+    /// // (x - 1)(x - 2)(x - 3)(x - 4)
+    /// let p = [24.0, -50.0, 35.0, -10.0, 1.0];
+    /// for z in p.roots()
+    /// {
+    ///     assert!(p.evaluate_as_polynomial(z).norm() < 1e-9);
+    /// }
+    /// ```
+    fn roots(self) -> Self::Roots;
+
+    /// Finds the real roots of a polynomial of degree one through four, using closed-form formulas
+    /// 
+    /// The roots are in the same order as in [roots](PolynomialRoots::roots), where roots with a nonzero imaginary part, and infinite roots, are [None](None).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 1)(x^2 + 1)
+    /// let roots = [-1.0, 1.0, -1.0, 1.0].real_roots();
+    /// 
+    /// assert_eq!(roots.into_iter().flatten().collect::<Vec<_>>(), [1.0]);
+    /// ```
+    fn real_roots(self) -> Self::RealRoots;
+}
+
+fn infinity<F>() -> Complex<F>
+where
+    F: Float
+{
+    Complex::new(F::infinity(), F::zero())
+}
+
+fn constant<F>(n: i8) -> F
+where
+    F: Float
+{
+    F::from(n).unwrap()
+}
+
+fn real_root<F>(z: Complex<F>) -> Option<F>
+where
+    F: Float
+{
+    (z.im.is_zero() && z.re.is_finite()).then_some(z.re)
+}
+
+fn linear_roots<F>([c0, c1]: [F; 2]) -> [Complex<F>; 1]
+where
+    F: Float
+{
+    if c1.is_zero()
+    {
+        return [infinity()]
+    }
+    [Complex::from(-c0/c1)]
+}
+
+fn quadratic_roots<F>([c0, c1, c2]: [F; 3]) -> [Complex<F>; 2]
+where
+    F: Float
+{
+    if c2.is_zero()
+    {
+        let [z] = linear_roots([c0, c1]);
+        return [infinity(), z]
+    }
+
+    let two = constant::<F>(2);
+    let discriminant = c1*c1 - constant::<F>(4)*c2*c0;
+    if discriminant < F::zero()
+    {
+        let re = -c1/(two*c2);
+        let im = (-discriminant).sqrt()/(two*c2).abs();
+        return [Complex::new(re, -im), Complex::new(re, im)]
+    }
+
+    // q = -(b + sign(b)sqrt(b^2 - 4ac))/2 never cancels, and the roots are q/a and c/q
+    let q = -(c1 + discriminant.sqrt().copysign(c1))/two;
+    if q.is_zero()
+    {
+        return [Complex::from(F::zero()); 2]
+    }
+    let (z1, z2) = (q/c2, c0/q);
+    if z1 <= z2
+    {
+        [Complex::from(z1), Complex::from(z2)]
+    }
+    else
+    {
+        [Complex::from(z2), Complex::from(z1)]
+    }
+}
+
+fn cubic_roots<F>([c0, c1, c2, c3]: [F; 4]) -> [Complex<F>; 3]
+where
+    F: Float + FloatConst
+{
+    if c3.is_zero()
+    {
+        let [z1, z2] = quadratic_roots([c0, c1, c2]);
+        return [infinity(), z1, z2]
+    }
+    if c3.abs() < c0.abs()
+    {
+        // Dividing by a small leading coefficient would make the other coefficients large, and the small roots would be lost to cancellation,
+        // so the reversed polynomial is solved instead, whose roots are the reciprocals
+        return cubic_roots([c3, c2, c1, c0])
+            .map(|z| newton_step([c0, c1, c2, c3], z.inv()))
+    }
+
+    let two = constant::<F>(2);
+    let three = constant::<F>(3);
+
+    // Depressed cubic t^3 + pt + q, where x = t - b/3
+    let (b, c, d) = (c2/c3, c1/c3, c0/c3);
+    let shift = b/three;
+    let p = c - b*shift;
+    let q = two*shift*shift*shift - shift*c + d;
+
+    let discriminant = q*q/constant(4) + p*p*p/constant(27);
+    if discriminant > F::zero()
+    {
+        // Cardano's formula, with one real root and a complex conjugate pair
+        // Of the two cube roots, u is chosen as the one that does not cancel, and v follows from uv = -p/3
+        let u = (-q/two - discriminant.sqrt().copysign(q)).cbrt();
+        let v = -p/(three*u);
+
+        let re = -(u + v)/two - shift;
+        let im = three.sqrt()/two*(u - v).abs();
+        return [Complex::from(u + v - shift), Complex::new(re, -im), Complex::new(re, im)]
+            .map(|z| newton_step([c0, c1, c2, c3], z))
+    }
+    if p.is_zero()
+    {
+        return [Complex::from(-shift); 3]
+    }
+
+    // Trigonometric method, with three real roots
+    let r = two*(-p/three).sqrt();
+    let phi = (three*q/(two*p)*(-three/p).sqrt())
+        .max(-F::one())
+        .min(F::one())
+        .acos()/three;
+    let tau = two*F::PI()/three;
+    [
+        Complex::from(r*(phi - tau*two).cos() - shift),
+        Complex::from(r*(phi - tau).cos() - shift),
+        Complex::from(r*phi.cos() - shift)
+    ].map(|z| newton_step([c0, c1, c2, c3], z))
+}
+
+fn quartic_roots<F>([c0, c1, c2, c3, c4]: [F; 5]) -> [Complex<F>; 4]
+where
+    F: Float + FloatConst
+{
+    if c4.is_zero()
+    {
+        let [z1, z2, z3] = cubic_roots([c0, c1, c2, c3]);
+        return [infinity(), z1, z2, z3]
+    }
+    if c4.abs() < c0.abs()
+    {
+        // Solved through the reversed polynomial for the same reason as a cubic
+        return quartic_roots([c4, c3, c2, c1, c0])
+            .map(|z| newton_step([c0, c1, c2, c3, c4], z.inv()))
+    }
+
+    let two = constant::<F>(2);
+
+    // Depressed quartic y^4 + py^2 + qy + r, where x = y - b/4
+    let (b, c, d, e) = (c3/c4, c2/c4, c1/c4, c0/c4);
+    let shift = b/constant(4);
+    let p = c - constant::<F>(6)*shift*shift;
+    let q = d - two*c*shift + constant::<F>(8)*shift*shift*shift;
+    let r = e - d*shift + c*shift*shift - constant::<F>(3)*shift*shift*shift*shift;
+
+    if q.is_zero()
+    {
+        // Biquadratic, solved as a quadratic in y^2
+        let [z1, z2] = quadratic_roots([r, p, F::one()]);
+        let (y1, y2) = (z1.sqrt(), z2.sqrt());
+        return [-y1 - shift, y1 - shift, -y2 - shift, y2 - shift]
+    }
+
+    // Ferrari's method, where m is a positive root of the resolvent cubic m^3 + pm^2 + (p^2/4 - r)m - q^2/8
+    let m = cubic_roots([-q*q/constant(8), p*p/constant(4) - r, p, F::one()])
+        .into_iter()
+        .filter_map(real_root)
+        .fold(F::neg_infinity(), F::max)
+        .max(F::epsilon());
+    let s = (two*m).sqrt();
+    let [y1, y2] = quadratic_roots([p/two + m + q/(two*s), -s, F::one()]);
+    let [y3, y4] = quadratic_roots([p/two + m - q/(two*s), s, F::one()]);
+
+    // The roots are polished with a Newton step on the original polynomial, since accuracy is lost through the resolvent
+    [y1, y2, y3, y4].map(|y| newton_step([c0, c1, c2, c3, c4], y - shift))
+}
+
+fn newton_step<F, const N: usize>(polynomial: [F; N], z: Complex<F>) -> Complex<F>
+where
+    F: Float
+{
+    let (y, dy) = polynomial.into_iter()
+        .rev()
+        .fold((Complex::from(F::zero()), Complex::from(F::zero())), |(y, dy), c| (y*z + c, dy*z + y));
+    let next = z - y/dy;
+    if next.re.is_finite() && next.im.is_finite()
+    {
+        next
+    }
+    else
+    {
+        z
+    }
+}
+
+macro_rules! impl_polynomial_roots {
+    ($($n:literal => $roots:ident),*) => {
+        $(
+            impl<F> PolynomialRoots for [F; $n]
+            where
+                F: Float + FloatConst
+            {
+                type Roots = [Complex<F>; $n - 1];
+                type RealRoots = [Option<F>; $n - 1];
+
+                fn roots(self) -> Self::Roots
+                {
+                    $roots(self)
+                }
+
+                fn real_roots(self) -> Self::RealRoots
+                {
+                    self.roots()
+                        .map(real_root)
+                }
+            }
+        )*
+    };
+}
+
+impl_polynomial_roots!(
+    2 => linear_roots,
+    3 => quadratic_roots,
+    4 => cubic_roots,
+    5 => quartic_roots
+);
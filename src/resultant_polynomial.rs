@@ -0,0 +1,321 @@
+use core::{alloc::Allocator, ops::{Add, Div, Mul, Neg, Sub}};
+
+use num_traits::{One, Zero};
+
+use super::*;
+
+pub trait ResultantPolynomial<Rhs>
+{
+    type Output;
+    type Matrix;
+
+    /// Yields the Sylvester matrix of two polynomials, whose determinant is their resultant
+    /// 
+    /// For polynomials of degrees `m` and `n`, the matrix is `(m + n)×(m + n)`. Its first `n` rows hold the coefficients of the first polynomial,
+    /// and its last `m` rows hold the coefficients of the second, each from the leading coefficient down, shifted one column to the right per row.
+    /// Multiplying a row vector of the coefficients of `u` and `v` by the matrix gives the coefficients of `u*p + v*q`, from the leading coefficient down.
+    /// Trailing zeros are ignored, and the matrix is empty if either polynomial is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // x^2 - 1 and 2x + 3
+    /// assert_eq!(
+    ///     [-1, 0, 1].sylvester_matrix([3, 2]),
+    ///     vec![
+    ///         vec![1, 0, -1],
+    ///         vec![2, 3, 0],
+    ///         vec![0, 2, 3]
+    ///     ]
+    /// );
+    /// ```
+    fn sylvester_matrix(self, rhs: Rhs) -> Self::Matrix;
+
+    /// Finds the resultant of two polynomials, as the determinant of their Sylvester matrix, using gaussian elimination with partial pivoting
+    /// 
+    /// The coefficients are expected to form a field (floating point numbers, rationals). The resultant is zero if and only if the polynomials share a root,
+    /// and is zero if either polynomial is zero. For integer coefficients, use [bareiss_resultant](BareissResultantPolynomial::bareiss_resultant) instead.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // x^2 - 1 shares the root 1 with x - 1, but no root with 2x + 3
+    /// assert_eq!([-1.0, 0.0, 1.0].resultant([-1.0, 1.0]), 0.0);
+    /// assert!(([-1.0f64, 0.0, 1.0].resultant([3.0, 2.0]) - 5.0).abs() < 1e-12);
+    /// ```
+    fn resultant(self, rhs: Rhs) -> Self::Output;
+}
+
+pub trait BareissResultantPolynomial<Rhs>
+{
+    type Output;
+
+    /// Finds the resultant of two polynomials, as the determinant of their Sylvester matrix, using the fraction-free Bareiss algorithm
+    /// 
+    /// Every division along the way is exact, so this works for integer coefficients without leaving the ring.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // x^2 - 1 and 2x + 3
+    /// assert_eq!([-1, 0, 1].bareiss_resultant([3, 2]), 5);
+    /// assert_eq!([-1, 0, 1].bareiss_resultant([-1, 1]), 0);
+    /// ```
+    fn bareiss_resultant(self, rhs: Rhs) -> Self::Output;
+}
+
+pub trait DiscriminantPolynomial
+{
+    type Output;
+
+    /// Finds the discriminant of a polynomial, `(-1)^(n(n - 1)/2)/a(n) res(p, p')`, using gaussian elimination with partial pivoting
+    /// 
+    /// The discriminant is zero if and only if the polynomial has a repeated root. Trailing zeros are ignored, and polynomials of degree less than one have a discriminant of zero.
+    /// For integer coefficients, use [bareiss_discriminant](DiscriminantPolynomial::bareiss_discriminant) instead.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // The discriminant of ax^2 + bx + c is b^2 - 4ac
+    /// assert!(([1.0f64, 3.0, 2.0].discriminant() - 1.0).abs() < 1e-12);
+    /// // (x - 1)^2 has a repeated root
+    /// assert_eq!([1.0, -2.0, 1.0].discriminant(), 0.0);
+    /// ```
+    fn discriminant(self) -> Self::Output;
+
+    /// Finds the discriminant of a polynomial using the fraction-free Bareiss algorithm
+    /// 
+    /// Every division along the way is exact, so this works for integer coefficients without leaving the ring.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // The discriminant of x^3 + px + q is -4p^3 - 27q^2
+    /// assert_eq!([2, -3, 0, 1].bareiss_discriminant(), 0);
+    /// assert_eq!([1, -1, 0, 1].bareiss_discriminant(), -23);
+    /// ```
+    fn bareiss_discriminant(self) -> Self::Output;
+}
+
+pub(crate) fn sylvester_matrix<C>(lhs: &[C], rhs: &[C]) -> Vec<Vec<C>>
+where
+    C: Zero + Copy
+{
+    let lhs = trim_polynomial(lhs.to_vec());
+    let rhs = trim_polynomial(rhs.to_vec());
+    if lhs.is_empty() || rhs.is_empty()
+    {
+        return vec![]
+    }
+
+    let m = lhs.len() - 1;
+    let n = rhs.len() - 1;
+    let row = |p: &[C], shift: usize| (0..m + n)
+        .map(|j| j.checked_sub(shift)
+            .and_then(|k| p.len().checked_sub(k + 1))
+            .map(|k| p[k])
+            .unwrap_or_else(C::zero)
+        ).collect::<Vec<_>>();
+    (0..n).map(|i| row(&lhs, i))
+        .chain((0..m).map(|i| row(&rhs, i)))
+        .collect()
+}
+
+/// The determinant by gaussian elimination with partial pivoting
+fn determinant<C>(mut matrix: Vec<Vec<C>>) -> C
+where
+    C: Zero + One + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + PartialOrd + Copy
+{
+    let n = matrix.len();
+    let mut det = C::one();
+    for k in 0..n
+    {
+        let pivot = (k..n)
+            .reduce(|i, j| if abs_coefficient(matrix[j][k]) > abs_coefficient(matrix[i][k]) {j} else {i})
+            .unwrap_or(k);
+        if matrix[pivot][k].is_zero()
+        {
+            return C::zero()
+        }
+        if pivot != k
+        {
+            matrix.swap(pivot, k);
+            det = -det;
+        }
+
+        let (upper, lower) = matrix.split_at_mut(k + 1);
+        let row = &upper[k];
+        det = det*row[k];
+        for other in lower.iter_mut()
+        {
+            let factor = other[k]/row[k];
+            for (a, &b) in other[k..].iter_mut()
+                .zip(row[k..].iter())
+            {
+                *a = *a - factor*b;
+            }
+        }
+    }
+    det
+}
+
+/// The determinant by fraction-free Bareiss elimination, where every division is exact
+fn bareiss_determinant<C>(mut matrix: Vec<Vec<C>>) -> C
+where
+    C: Zero + One + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + Copy
+{
+    let n = matrix.len();
+    if n == 0
+    {
+        return C::one()
+    }
+    let mut sign = C::one();
+    let mut previous = C::one();
+    for k in 0..n
+    {
+        let Some(pivot) = (k..n).find(|&i| !matrix[i][k].is_zero())
+        else
+        {
+            return C::zero()
+        };
+        if pivot != k
+        {
+            matrix.swap(pivot, k);
+            sign = -sign;
+        }
+
+        let (upper, lower) = matrix.split_at_mut(k + 1);
+        let row = &upper[k];
+        for other in lower.iter_mut()
+        {
+            for j in k + 1..n
+            {
+                other[j] = (other[j]*row[k] - other[k]*row[j])/previous;
+            }
+            other[k] = C::zero();
+        }
+        previous = row[k];
+    }
+    sign*matrix[n - 1][n - 1]
+}
+
+/// The discriminant from the resultant of the polynomial and its derivative
+fn discriminant_by<C>(polynomial: &[C], resultant: impl Fn(&[C], &[C]) -> C) -> C
+where
+    C: Zero + One + Add<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + Copy
+{
+    let p = trim_polynomial(polynomial.to_vec());
+    if p.len() <= 1
+    {
+        return C::zero()
+    }
+    let n = p.len() - 1;
+    let r = resultant(&p, &p.as_slice().derive_polynomial())/p[n];
+    if (n*(n - 1)/2) % 2 == 1 {-r} else {r}
+}
+
+pub(crate) fn resultant<C>(lhs: &[C], rhs: &[C]) -> C
+where
+    C: Zero + One + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + PartialOrd + Copy
+{
+    if trim_polynomial(lhs.to_vec()).is_empty() || trim_polynomial(rhs.to_vec()).is_empty()
+    {
+        return C::zero()
+    }
+    determinant(sylvester_matrix(lhs, rhs))
+}
+
+pub(crate) fn bareiss_resultant<C>(lhs: &[C], rhs: &[C]) -> C
+where
+    C: Zero + One + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + Copy
+{
+    if trim_polynomial(lhs.to_vec()).is_empty() || trim_polynomial(rhs.to_vec()).is_empty()
+    {
+        return C::zero()
+    }
+    bareiss_determinant(sylvester_matrix(lhs, rhs))
+}
+
+macro_rules! impl_resultant_polynomial {
+    ($(<{$($generics:tt)+}>)? [$rhs:ty] for $type:ty $(where $($where:tt)+)?) => {
+        impl<C, $($($generics)+)?> ResultantPolynomial<$rhs> for $type
+        where
+            C: Zero + One + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + PartialOrd + Copy
+            $(,$($where)+)?
+        {
+            type Output = C;
+            type Matrix = Vec<Vec<C>>;
+
+            fn sylvester_matrix(self, rhs: $rhs) -> Self::Matrix
+            {
+                sylvester_matrix(&self[..], &rhs[..])
+            }
+
+            fn resultant(self, rhs: $rhs) -> Self::Output
+            {
+                resultant(&self[..], &rhs[..])
+            }
+        }
+
+        impl<C, $($($generics)+)?> BareissResultantPolynomial<$rhs> for $type
+        where
+            C: Zero + One + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + Copy
+            $(,$($where)+)?
+        {
+            type Output = C;
+
+            fn bareiss_resultant(self, rhs: $rhs) -> Self::Output
+            {
+                bareiss_resultant(&self[..], &rhs[..])
+            }
+        }
+    };
+}
+
+impl_resultant_polynomial!([&[C]] for &[C]);
+impl_resultant_polynomial!(<{A}> [Vec<C, A>] for &[C] where A: Allocator);
+impl_resultant_polynomial!(<{const N: usize}> [[C; N]] for &[C]);
+
+impl_resultant_polynomial!(<{A}> [&[C]] for Vec<C, A> where A: Allocator);
+impl_resultant_polynomial!(<{A1, A2}> [Vec<C, A2>] for Vec<C, A1> where A1: Allocator, A2: Allocator);
+impl_resultant_polynomial!(<{A, const N: usize}> [[C; N]] for Vec<C, A> where A: Allocator);
+
+impl_resultant_polynomial!(<{const N: usize}> [&[C]] for [C; N]);
+impl_resultant_polynomial!(<{A, const N: usize}> [Vec<C, A>] for [C; N] where A: Allocator);
+impl_resultant_polynomial!(<{const N1: usize, const N2: usize}> [[C; N2]] for [C; N1]);
+
+macro_rules! impl_discriminant_polynomial {
+    ($(<{$($generics:tt)+}>)? for $type:ty $(where $($where:tt)+)?) => {
+        impl<C, $($($generics)+)?> DiscriminantPolynomial for $type
+        where
+            C: Zero + One + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + PartialOrd + Copy
+            $(,$($where)+)?
+        {
+            type Output = C;
+
+            fn discriminant(self) -> Self::Output
+            {
+                discriminant_by(&self[..], resultant)
+            }
+
+            fn bareiss_discriminant(self) -> Self::Output
+            {
+                discriminant_by(&self[..], bareiss_resultant)
+            }
+        }
+    };
+}
+
+impl_discriminant_polynomial!(for &[C]);
+impl_discriminant_polynomial!(<{A}> for Vec<C, A> where A: Allocator);
+impl_discriminant_polynomial!(<{const N: usize}> for [C; N]);
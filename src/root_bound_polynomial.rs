@@ -0,0 +1,180 @@
+use core::alloc::Allocator;
+
+use num_traits::Float;
+
+pub trait RootBoundPolynomial
+{
+    type Bound;
+
+    /// Cauchy's bound on the modulus of the roots, `1 + max|a(k)/a(n)|`
+    /// 
+    /// For every bound, trailing zeros are ignored, the bound of a nonzero constant is zero, since it has no roots,
+    /// and the bound of the zero polynomial is infinite, since every point is a root.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 1)(x + 2)(x - 3) = x^3 - 2x^2 - 5x + 6
+    /// let p = [6.0, -5.0, -2.0, 1.0];
+    /// 
+    /// assert_eq!(p.root_bound_cauchy(), 7.0);
+    /// assert_eq!(p.root_bound_lagrange(), 13.0);
+    /// assert!(p.root_bound_fujiwara() >= 3.0);
+    /// assert_eq!(p.root_bound(), p.root_bound_fujiwara());
+    /// ```
+    fn root_bound_cauchy(self) -> Self::Bound;
+
+    /// Lagrange's bound on the modulus of the roots, `max(1, sum|a(k)/a(n)|)`
+    fn root_bound_lagrange(self) -> Self::Bound;
+
+    /// Fujiwara's bound on the modulus of the roots, `2max(|a(n - 1)/a(n)|, |a(n - 2)/a(n)|^(1/2), ..., |a(0)/2a(n)|^(1/n))`
+    /// 
+    /// Unlike Cauchy's and Lagrange's bounds, this bound scales with the roots, and is never more than `2n` times the largest root.
+    fn root_bound_fujiwara(self) -> Self::Bound;
+
+    /// The smallest of the bounds on the modulus of the roots
+    fn root_bound(self) -> Self::Bound;
+}
+
+/// Yields the coefficients divided by the leading coefficient, excluding the leading coefficient itself,
+/// or the bound itself if there are no roots, or every point is a root
+fn normalized<F>(polynomial: &[F]) -> Result<impl DoubleEndedIterator<Item = F> + ExactSizeIterator + '_, F>
+where
+    F: Float
+{
+    let Some(n) = polynomial.iter()
+        .rposition(|c| !c.is_zero())
+    else
+    {
+        return Err(F::infinity())
+    };
+    if n == 0
+    {
+        return Err(F::zero())
+    }
+    let lead = polynomial[n].abs();
+    Ok(polynomial[..n].iter()
+        .map(move |c| c.abs()/lead)
+    )
+}
+
+pub(crate) fn root_bound_cauchy<F>(polynomial: &[F]) -> F
+where
+    F: Float
+{
+    normalized(polynomial)
+        .map(|a| a.fold(F::zero(), F::max) + F::one())
+        .unwrap_or_else(|bound| bound)
+}
+
+pub(crate) fn root_bound_lagrange<F>(polynomial: &[F]) -> F
+where
+    F: Float
+{
+    normalized(polynomial)
+        .map(|a| a.fold(F::zero(), |s, a| s + a).max(F::one()))
+        .unwrap_or_else(|bound| bound)
+}
+
+pub(crate) fn root_bound_fujiwara<F>(polynomial: &[F]) -> F
+where
+    F: Float
+{
+    normalized(polynomial)
+        .map(|a| {
+            let n = a.len();
+            let two = F::one() + F::one();
+            a.enumerate()
+                .map(|(k, a)| {
+                    let a = if k == 0 {a/two} else {a};
+                    a.powf(F::from(n - k).unwrap().recip())
+                }).fold(F::zero(), F::max)*two
+        }).unwrap_or_else(|bound| bound)
+}
+
+impl<F> RootBoundPolynomial for &[F]
+where
+    F: Float
+{
+    type Bound = F;
+
+    fn root_bound_cauchy(self) -> Self::Bound
+    {
+        root_bound_cauchy(self)
+    }
+
+    fn root_bound_lagrange(self) -> Self::Bound
+    {
+        root_bound_lagrange(self)
+    }
+
+    fn root_bound_fujiwara(self) -> Self::Bound
+    {
+        root_bound_fujiwara(self)
+    }
+
+    fn root_bound(self) -> Self::Bound
+    {
+        root_bound_cauchy(self)
+            .min(root_bound_lagrange(self))
+            .min(root_bound_fujiwara(self))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, A> RootBoundPolynomial for Vec<F, A>
+where
+    A: Allocator,
+    F: Float
+{
+    type Bound = F;
+
+    fn root_bound_cauchy(self) -> Self::Bound
+    {
+        self.as_slice().root_bound_cauchy()
+    }
+
+    fn root_bound_lagrange(self) -> Self::Bound
+    {
+        self.as_slice().root_bound_lagrange()
+    }
+
+    fn root_bound_fujiwara(self) -> Self::Bound
+    {
+        self.as_slice().root_bound_fujiwara()
+    }
+
+    fn root_bound(self) -> Self::Bound
+    {
+        self.as_slice().root_bound()
+    }
+}
+
+impl<F, const N: usize> RootBoundPolynomial for [F; N]
+where
+    F: Float
+{
+    type Bound = F;
+
+    fn root_bound_cauchy(self) -> Self::Bound
+    {
+        self.as_slice().root_bound_cauchy()
+    }
+
+    fn root_bound_lagrange(self) -> Self::Bound
+    {
+        self.as_slice().root_bound_lagrange()
+    }
+
+    fn root_bound_fujiwara(self) -> Self::Bound
+    {
+        self.as_slice().root_bound_fujiwara()
+    }
+
+    fn root_bound(self) -> Self::Bound
+    {
+        self.as_slice().root_bound()
+    }
+}
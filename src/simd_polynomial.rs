@@ -0,0 +1,119 @@
+use core::{alloc::Allocator, simd::Simd};
+
+pub trait SimdPolynomial<F>
+{
+    /// Evaluates a polynomial at many points using SIMD lanes, and writes the values into `y`
+    /// 
+    /// The points are evaluated in chunks the width of a SIMD vector, using Horner's method on every lane at once,
+    /// and the remaining points are evaluated one by one. The coefficients are borrowed, so they are not copied per point.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `x` and `y` have different lengths.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// let p = [1.0f32, 2.0, 3.0];
+    /// let x: Vec<f32> = (0..100).map(|i| i as f32*0.01).collect();
+    /// let mut y = vec![0.0; x.len()];
+    /// 
+    /// p.evaluate_simd_into(&x, &mut y);
+    /// for (x, y) in x.into_iter().zip(y)
+    /// {
+    ///     assert_eq!(y, p.evaluate_as_polynomial(x));
+    /// }
+    /// ```
+    fn evaluate_simd_into(&self, x: &[F], y: &mut [F]);
+
+    /// Evaluates a polynomial at many points using SIMD lanes
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// let p = [1.0f64, -3.0, 0.0, 1.0];
+    /// 
+    /// assert_eq!(
+    ///     p.evaluate_simd(&[0.0, 1.0, 2.0, 3.0, 4.0]),
+    ///     vec![1.0, -1.0, 3.0, 19.0, 53.0]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn evaluate_simd(&self, x: &[F]) -> Vec<F>;
+}
+
+macro_rules! impl_simd_polynomial {
+    ($float:ty, $lanes:literal) => {
+        impl SimdPolynomial<$float> for [$float]
+        {
+            fn evaluate_simd_into(&self, x: &[$float], y: &mut [$float])
+            {
+                assert_eq!(x.len(), y.len(), "there must be as many values as points");
+
+                let mut x_chunks = x.chunks_exact($lanes);
+                let mut y_chunks = y.chunks_exact_mut($lanes);
+                for (x, y) in (&mut x_chunks).zip(&mut y_chunks)
+                {
+                    let x = Simd::<$float, $lanes>::from_slice(x);
+                    self.iter()
+                        .rev()
+                        .fold(Simd::splat(0.0), |y, &c| y*x + Simd::splat(c))
+                        .copy_to_slice(y);
+                }
+                for (&x, y) in x_chunks.remainder()
+                    .iter()
+                    .zip(y_chunks.into_remainder())
+                {
+                    *y = self.iter()
+                        .rev()
+                        .fold(0.0, |y, &c| y*x + c);
+                }
+            }
+
+            #[cfg(feature = "std")]
+            fn evaluate_simd(&self, x: &[$float]) -> Vec<$float>
+            {
+                let mut y = vec![0.0; x.len()];
+                self.evaluate_simd_into(x, &mut y);
+                y
+            }
+        }
+
+        impl<const N: usize> SimdPolynomial<$float> for [$float; N]
+        {
+            fn evaluate_simd_into(&self, x: &[$float], y: &mut [$float])
+            {
+                self.as_slice().evaluate_simd_into(x, y)
+            }
+
+            #[cfg(feature = "std")]
+            fn evaluate_simd(&self, x: &[$float]) -> Vec<$float>
+            {
+                self.as_slice().evaluate_simd(x)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<A> SimdPolynomial<$float> for Vec<$float, A>
+        where
+            A: Allocator
+        {
+            fn evaluate_simd_into(&self, x: &[$float], y: &mut [$float])
+            {
+                self.as_slice().evaluate_simd_into(x, y)
+            }
+
+            fn evaluate_simd(&self, x: &[$float]) -> Vec<$float>
+            {
+                self.as_slice().evaluate_simd(x)
+            }
+        }
+    };
+}
+
+impl_simd_polynomial!(f32, 8);
+impl_simd_polynomial!(f64, 4);
@@ -0,0 +1,189 @@
+use core::{alloc::Allocator, ops::{Add, Div, Mul, Neg, Rem, Sub}};
+
+use num_traits::{One, Zero};
+
+use super::*;
+
+pub trait SquareFreePolynomial
+{
+    type Factors;
+    type Tolerance;
+
+    /// Splits a polynomial into square-free factors using Yun's algorithm, and yields each factor with its multiplicity
+    /// 
+    /// The coefficients are expected to form a field (floating point numbers, rationals). The factors are monic, pairwise coprime, and have no repeated roots,
+    /// and the polynomial is its leading coefficient times the product of every factor raised to its multiplicity.
+    /// Only factors of positive degree are yielded, in ascending order of multiplicity, so a constant polynomial has no factors.
+    /// For integer coefficients, use [primitive_square_free_decomposition](PrimitiveSquareFreePolynomial::primitive_square_free_decomposition) instead,
+    /// and for floating point coefficients with inexact repeated roots, use [square_free_decomposition_with_tolerance](SquareFreePolynomial::square_free_decomposition_with_tolerance).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 1)(x + 2)^2 = x^3 + 3x^2 - 4
+    /// assert_eq!(
+    ///     [-4.0, 0.0, 3.0, 1.0].square_free_decomposition(),
+    ///     vec![(vec![-1.0, 1.0], 1), (vec![2.0, 1.0], 2)]
+    /// );
+    /// ```
+    fn square_free_decomposition(self) -> Self::Factors;
+
+    /// Splits a polynomial into square-free factors using Yun's algorithm, treating small remainder coefficients as zero
+    /// 
+    /// The greatest common divisors are found with [gcd_polynomial_with_tolerance](GcdPolynomial::gcd_polynomial_with_tolerance),
+    /// so that roots which are only repeated up to rounding errors are still grouped together.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x - 0.1)^3 = x^3 - 0.3x^2 + 0.03x - 0.001
+    /// let factors = [-0.001f64, 0.03, -0.3, 1.0].square_free_decomposition_with_tolerance(1e-9);
+    /// 
+    /// assert_eq!(factors.len(), 1);
+    /// assert_eq!(factors[0].1, 3);
+    /// assert!((factors[0].0[0] + 0.1).abs() < 1e-9);
+    /// ```
+    fn square_free_decomposition_with_tolerance(self, tolerance: Self::Tolerance) -> Self::Factors;
+}
+
+pub trait PrimitiveSquareFreePolynomial
+{
+    type Factors;
+
+    /// Splits an integer polynomial into square-free factors using Yun's algorithm, and yields each factor with its multiplicity
+    /// 
+    /// The greatest common divisors are found with [subresultant_gcd_polynomial](SubresultantGcdPolynomial::subresultant_gcd_polynomial), and every division is exact.
+    /// The factors are primitive with positive leading coefficients, and the polynomial is its content, up to sign, times the product of every factor raised to its multiplicity.
+    /// Only factors of positive degree are yielded, in ascending order of multiplicity.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // 2(2x - 1)^2(x + 1) = 8x^3 - 6x + 2
+    /// assert_eq!(
+    ///     [2, -6, 0, 8].primitive_square_free_decomposition(),
+    ///     vec![(vec![1, 1], 1), (vec![-1, 2], 2)]
+    /// );
+    /// ```
+    fn primitive_square_free_decomposition(self) -> Self::Factors;
+}
+
+/// Yun's algorithm, where `gcd` yields a normalized greatest common divisor, and `trim` removes negligible leading coefficients
+pub(crate) fn yun_square_free_decomposition<C>(
+    polynomial: &[C],
+    gcd: impl Fn(&[C], &[C]) -> Vec<C>,
+    trim: impl Fn(Vec<C>, &[C]) -> Vec<C>
+) -> Vec<(Vec<C>, usize)>
+where
+    C: Zero + One + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Copy
+{
+    let div = |lhs: &[C], rhs: &[C]| {
+        let (q, _) = lhs.div_rem_polynomial(rhs)
+            .unwrap_or_else(|_| unreachable!());
+        trim_polynomial(q)
+    };
+    let sub = |mut lhs: Vec<C>, rhs: Vec<C>| {
+        lhs.resize(lhs.len().max(rhs.len()), C::zero());
+        for (l, r) in lhs.iter_mut()
+            .zip(rhs)
+        {
+            *l = *l - r;
+        }
+        trim_polynomial(lhs)
+    };
+
+    let p = trim_polynomial(polynomial.to_vec());
+    if p.len() <= 1
+    {
+        return vec![]
+    }
+
+    let dp = p.as_slice().derive_polynomial();
+    let a = gcd(&p, &dp);
+    let mut b = div(&p, &a);
+    let c = div(&dp, &a);
+    let mut d = trim(sub(c, b.as_slice().derive_polynomial()), &b);
+
+    let mut factors = vec![];
+    let mut multiplicity = 1;
+    while b.len() > 1
+    {
+        let a = gcd(&b, &d);
+        b = div(&b, &a);
+        let c = div(&d, &a);
+        d = trim(sub(c, b.as_slice().derive_polynomial()), &b);
+        if a.len() > 1
+        {
+            factors.push((a, multiplicity));
+        }
+        multiplicity += 1;
+    }
+    factors
+}
+
+macro_rules! impl_square_free_polynomial {
+    ($(<{$($generics:tt)+}>)? for $type:ty $(where $($where:tt)+)?) => {
+        impl<C, $($($generics)+)?> SquareFreePolynomial for $type
+        where
+            C: Zero + One + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Neg<Output = C> + PartialOrd + Copy
+            $(,$($where)+)?
+        {
+            type Factors = Vec<(Vec<C>, usize)>;
+            type Tolerance = C;
+
+            fn square_free_decomposition(self) -> Self::Factors
+            {
+                yun_square_free_decomposition(
+                    &self[..],
+                    |a, b| euclid_gcd_polynomial(a, b),
+                    |d, _| d
+                )
+            }
+
+            fn square_free_decomposition_with_tolerance(self, tolerance: C) -> Self::Factors
+            {
+                yun_square_free_decomposition(
+                    &self[..],
+                    |a, b| euclid_gcd_polynomial_with_tolerance(a, b, tolerance),
+                    |mut d, b| {
+                        let scale = b.iter()
+                            .map(|&c| abs_coefficient(c))
+                            .fold(C::zero(), |a, b| if b > a {b} else {a});
+                        while d.last().is_some_and(|&c| abs_coefficient(c) <= tolerance*scale)
+                        {
+                            d.pop();
+                        }
+                        d
+                    }
+                )
+            }
+        }
+
+        impl<C, $($($generics)+)?> PrimitiveSquareFreePolynomial for $type
+        where
+            C: Zero + One + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Rem<Output = C> + Neg<Output = C> + PartialOrd + Copy
+            $(,$($where)+)?
+        {
+            type Factors = Vec<(Vec<C>, usize)>;
+
+            fn primitive_square_free_decomposition(self) -> Self::Factors
+            {
+                yun_square_free_decomposition(
+                    &self[..],
+                    |a, b| primitive_polynomial(subresultant_gcd_polynomial(a, b)),
+                    |d, _| d
+                )
+            }
+        }
+    };
+}
+
+impl_square_free_polynomial!(for &[C]);
+impl_square_free_polynomial!(<{A}> for Vec<C, A> where A: Allocator);
+impl_square_free_polynomial!(<{const N: usize}> for [C; N]);
@@ -0,0 +1,385 @@
+use core::{alloc::Allocator, fmt::Display, ops::{Add, Div, Mul, Neg, Sub}};
+
+use num_traits::{One, Zero};
+
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IsolateRealRootsError
+{
+    /// Bisection reached an interval that can not be split any further, while it still holds more than one root
+    /// 
+    /// This happens when rounding noise in the Sturm sequence makes the roots miscounted, or the roots are closer than the precision of the coefficients.
+    Unresolved
+}
+
+impl Display for IsolateRealRootsError
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        match self
+        {
+            Self::Unresolved => write!(f, "real root isolation could not separate the roots")
+        }
+    }
+}
+
+impl std::error::Error for IsolateRealRootsError
+{
+
+}
+
+/// The Sturm sequence of a polynomial, `p0 = p`, `p1 = p'` and `p(k + 1) = -rem(p(k - 1), p(k))`, ending with the last nonzero remainder
+/// 
+/// The number of distinct real roots in `(a, b]` is the number of sign changes in the sequence at `a` minus the number of sign changes at `b`.
+/// If the polynomial has multiple roots, every polynomial in the sequence is divided by the last one, so that the sequence starts with the square-free part of the polynomial.
+/// 
+/// The count is only guaranteed when the coefficients are exact, such as rationals. With floating point numbers,
+/// a remainder that should vanish may be left as rounding noise, so multiple roots are not detected, and the count may be wrong.
+/// Floating point coefficients must therefore be of a square-free polynomial, and even then the roots should be well separated.
+/// 
+/// The coefficients only need to be [Clone](Clone), so big rationals such as [BigRational](num_rational::BigRational) can be used.
+/// Fixed-size rationals such as `Ratio<i64>` overflow when isolating close roots, since every bisection doubles the denominator of the midpoint,
+/// and evaluating the sequence there raises it to the power of the degree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SturmSequence<C>
+{
+    sequence: Vec<Vec<C>>
+}
+
+impl<C> SturmSequence<C>
+where
+    C: Zero + One + Neg<Output = C> + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + PartialOrd + Default + Clone
+{
+    /// Builds the Sturm sequence of a polynomial, which is empty for the zero polynomial
+    /// 
+    /// Multiple roots are only handled with exact coefficients, such as [Ratio](num_rational::Ratio). Floating point coefficients must be of a square-free polynomial.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use num_rational::Ratio;
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x + 1)(x - 1)^2(x - 2), where the double root is only counted once
+    /// let p = [-2, 3, 1, -3, 1].map(Ratio::<i64>::from);
+    /// let sturm = SturmSequence::new(&p);
+    /// 
+    /// assert_eq!(sturm.count_real_roots(), 3);
+    /// assert_eq!(sturm.count_real_roots_in(Ratio::from(0), Ratio::from(1)), 1);
+    /// ```
+    pub fn new(polynomial: &[C]) -> Self
+    {
+        let polynomial = trim_polynomial(polynomial.to_vec());
+        if polynomial.is_empty()
+        {
+            return Self {
+                sequence: vec![]
+            }
+        }
+
+        let mut sequence = vec![];
+        let mut next = trim_polynomial(derive(&polynomial));
+        sequence.push(polynomial);
+        while !next.is_empty()
+        {
+            let prev = &sequence[sequence.len() - 1];
+            let (_, r) = div_rem(prev, &next);
+            sequence.push(next);
+            next = trim_polynomial(r).into_iter()
+                .map(|c| -c)
+                .collect();
+        }
+
+        // The last polynomial is the greatest common divisor of the polynomial and its derivative, which vanishes at every multiple root
+        let gcd = sequence[sequence.len() - 1].clone();
+        if gcd.len() > 1
+        {
+            for p in sequence.iter_mut()
+            {
+                *p = div_rem(p, &gcd).0;
+            }
+        }
+
+        Self {
+            sequence
+        }
+    }
+
+    /// The polynomials of the sequence, starting with the polynomial itself, or its square-free part
+    pub fn polynomials(&self) -> &[Vec<C>]
+    {
+        &self.sequence
+    }
+
+    /// The number of sign changes in the sequence evaluated at `x`, where zeros are skipped
+    pub fn sign_changes(&self, x: C) -> usize
+    {
+        count_sign_changes(self.sequence.iter()
+            .map(|p| evaluate(p, &x))
+        )
+    }
+
+    /// The number of sign changes in the sequence at positive or negative infinity, which follows from the leading coefficients
+    pub fn sign_changes_at_infinity(&self, negative: bool) -> usize
+    {
+        count_sign_changes(self.sequence.iter()
+            .map(|p| {
+                let lead = p[p.len() - 1].clone();
+                if negative && p.len() % 2 == 0 {-lead} else {lead}
+            })
+        )
+    }
+
+    /// Counts the distinct real roots in the interval `(a, b]`, where `a < b`
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x + 1)(x - 1)(x - 2)
+    /// let sturm = [2.0, -1.0, -2.0, 1.0].sturm_sequence();
+    /// 
+    /// assert_eq!(sturm.count_real_roots_in(-2.0, 3.0), 3);
+    /// assert_eq!(sturm.count_real_roots_in(0.0, 1.0), 1);
+    /// assert_eq!(sturm.count_real_roots_in(1.0, 1.5), 0);
+    /// ```
+    pub fn count_real_roots_in(&self, a: C, b: C) -> usize
+    {
+        self.sign_changes(a).saturating_sub(self.sign_changes(b))
+    }
+
+    /// Counts all the distinct real roots
+    pub fn count_real_roots(&self) -> usize
+    {
+        self.sign_changes_at_infinity(true).saturating_sub(self.sign_changes_at_infinity(false))
+    }
+
+    /// Isolates every distinct real root in its own interval `(a, b]`, by bisecting until each interval holds exactly one root
+    /// 
+    /// The intervals are disjoint and sorted in ascending order, and bisection starts from Cauchy's bound on the roots.
+    /// 
+    /// # Errors
+    /// 
+    /// Yields [IsolateRealRootsError::Unresolved](IsolateRealRootsError::Unresolved) if an interval holding more than one root can not be bisected any further,
+    /// which may happen with floating point coefficients.
+    /// 
+    /// Close roots take many bisections, which fixed-size rationals overflow on, so exact coefficients of such polynomials should be [BigRational](num_rational::BigRational).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use num_rational::Ratio;
+    /// use polynomial_ops::*;
+    /// 
+    /// // (x + 1)(x - 1)^2(x - 2)
+    /// let sturm = [-2, 3, 1, -3, 1].map(Ratio::<i64>::from).sturm_sequence();
+    /// let intervals = sturm.isolate_real_roots().unwrap();
+    /// 
+    /// assert_eq!(intervals.len(), 3);
+    /// for (root, (a, b)) in [-1, 1, 2].map(Ratio::from).into_iter().zip(intervals)
+    /// {
+    ///     assert!(a < root && root <= b);
+    /// }
+    /// ```
+    pub fn isolate_real_roots(&self) -> Result<Vec<(C, C)>, IsolateRealRootsError>
+    {
+        let Some(polynomial) = self.sequence.first()
+        else
+        {
+            return Ok(vec![])
+        };
+
+        let lead = abs_coefficient(polynomial[polynomial.len() - 1].clone());
+        let bound = polynomial[..polynomial.len() - 1].iter()
+            .map(|c| abs_coefficient(c.clone())/lead.clone())
+            .fold(C::zero(), |a, b| if b > a {b} else {a}) + C::one();
+        let two = C::one() + C::one();
+
+        let mut intervals = vec![];
+        let mut stack = vec![(-bound.clone(), bound, self.count_real_roots())];
+        while let Some((a, b, count)) = stack.pop()
+        {
+            if count == 0
+            {
+                continue
+            }
+            if count == 1
+            {
+                intervals.push((a, b));
+                continue
+            }
+            let mid = (a.clone() + b.clone())/two.clone();
+            if !(a < mid && mid < b)
+            {
+                return Err(IsolateRealRootsError::Unresolved)
+            }
+
+            let left = self.count_real_roots_in(a.clone(), mid.clone());
+            stack.push((mid.clone(), b, count.saturating_sub(left)));
+            stack.push((a, mid, left));
+        }
+        Ok(intervals)
+    }
+}
+
+/// Evaluates a polynomial with Horner's method, cloning the coefficients
+fn evaluate<C>(polynomial: &[C], x: &C) -> C
+where
+    C: Add<Output = C> + Mul<Output = C> + Default + Clone
+{
+    polynomial.iter()
+        .rev()
+        .cloned()
+        .reduce(|y, c| y*x.clone() + c)
+        .unwrap_or_default()
+}
+
+fn derive<C>(polynomial: &[C]) -> Vec<C>
+where
+    C: Zero + One + Add<Output = C> + Mul<Output = C> + Clone
+{
+    polynomial.iter()
+        .skip(1)
+        .scan(C::zero(), |n, c| {
+            *n = n.clone() + C::one();
+            Some(c.clone()*n.clone())
+        }).collect()
+}
+
+/// Divides by a trimmed nonzero polynomial using long division, cloning the coefficients
+fn div_rem<C>(lhs: &[C], rhs: &[C]) -> (Vec<C>, Vec<C>)
+where
+    C: Zero + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + Clone
+{
+    let lead = rhs[rhs.len() - 1].clone();
+    let mut r = lhs.to_vec();
+    r.resize(lhs.len().max(rhs.len() - 1), C::zero());
+
+    let len = (lhs.len() + 1).saturating_sub(rhs.len());
+    let mut q = vec![C::zero(); len];
+    for k in (0..len).rev()
+    {
+        let qk = r[k + rhs.len() - 1].clone()/lead.clone();
+        for (r, c) in r[k..k + rhs.len()].iter_mut()
+            .zip(rhs)
+        {
+            *r = r.clone() - qk.clone()*c.clone();
+        }
+        q[k] = qk;
+    }
+    r.truncate(rhs.len() - 1);
+
+    (q, r)
+}
+
+fn count_sign_changes<C>(values: impl Iterator<Item = C>) -> usize
+where
+    C: Zero + PartialOrd
+{
+    values.filter(|y| !y.is_zero())
+        .map(|y| y > C::zero())
+        .fold((0, None), |(n, prev), positive| match prev
+        {
+            Some(prev) if prev != positive => (n + 1, Some(positive)),
+            _ => (n, Some(positive))
+        }).0
+}
+
+pub trait SturmPolynomial
+{
+    type Coefficient;
+
+    /// Builds the [Sturm sequence](SturmSequence) of a polynomial, for counting and isolating its real roots
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use polynomial_ops::*;
+    /// 
+    /// // x^3 - x has the roots -1, 0 and 1
+    /// let sturm = vec![0.0, -1.0, 0.0, 1.0].sturm_sequence();
+    /// 
+    /// assert_eq!(sturm.count_real_roots(), 3);
+    /// ```
+    fn sturm_sequence(self) -> SturmSequence<Self::Coefficient>;
+
+    /// Counts the distinct real roots in the interval `(a, b]`, where `a < b`
+    /// 
+    /// See [SturmSequence::count_real_roots_in](SturmSequence::count_real_roots_in).
+    fn count_real_roots_in(self, a: Self::Coefficient, b: Self::Coefficient) -> usize;
+
+    /// Isolates every distinct real root in its own interval `(a, b]`
+    /// 
+    /// See [SturmSequence::isolate_real_roots](SturmSequence::isolate_real_roots).
+    #[allow(clippy::type_complexity)]
+    fn isolate_real_roots(self) -> Result<Vec<(Self::Coefficient, Self::Coefficient)>, IsolateRealRootsError>;
+}
+
+impl<C> SturmPolynomial for &[C]
+where
+    C: Zero + One + Neg<Output = C> + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + PartialOrd + Default + Clone
+{
+    type Coefficient = C;
+
+    fn sturm_sequence(self) -> SturmSequence<C>
+    {
+        SturmSequence::new(self)
+    }
+
+    fn count_real_roots_in(self, a: C, b: C) -> usize
+    {
+        self.sturm_sequence().count_real_roots_in(a, b)
+    }
+
+    fn isolate_real_roots(self) -> Result<Vec<(C, C)>, IsolateRealRootsError>
+    {
+        self.sturm_sequence().isolate_real_roots()
+    }
+}
+
+impl<C, A> SturmPolynomial for Vec<C, A>
+where
+    A: Allocator,
+    C: Zero + One + Neg<Output = C> + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + PartialOrd + Default + Clone
+{
+    type Coefficient = C;
+
+    fn sturm_sequence(self) -> SturmSequence<C>
+    {
+        self.as_slice().sturm_sequence()
+    }
+
+    fn count_real_roots_in(self, a: C, b: C) -> usize
+    {
+        self.as_slice().count_real_roots_in(a, b)
+    }
+
+    fn isolate_real_roots(self) -> Result<Vec<(C, C)>, IsolateRealRootsError>
+    {
+        self.as_slice().isolate_real_roots()
+    }
+}
+
+impl<C, const N: usize> SturmPolynomial for [C; N]
+where
+    C: Zero + One + Neg<Output = C> + Add<Output = C> + Sub<Output = C> + Mul<Output = C> + Div<Output = C> + PartialOrd + Default + Clone
+{
+    type Coefficient = C;
+
+    fn sturm_sequence(self) -> SturmSequence<C>
+    {
+        self.as_slice().sturm_sequence()
+    }
+
+    fn count_real_roots_in(self, a: C, b: C) -> usize
+    {
+        self.as_slice().count_real_roots_in(a, b)
+    }
+
+    fn isolate_real_roots(self) -> Result<Vec<(C, C)>, IsolateRealRootsError>
+    {
+        self.as_slice().isolate_real_roots()
+    }
+}